@@ -51,8 +51,7 @@ fn do_decode_in_slice_bench(b: &mut Bencher, &size: &usize) {
 
     let encoded = g60::encode(&input);
 
-    let mut buffer = Vec::new();
-    buffer.resize(size, 0);
+    let mut buffer = vec![0; size];
 
     b.iter(|| {
         g60::decode_in_slice(encoded.as_str(), &mut buffer).unwrap();
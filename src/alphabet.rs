@@ -0,0 +1,303 @@
+use crate::constants::{ENCODED_TO_UTF8_MAP, UTF8_TO_ENCODED_MAP};
+use crate::errors::{AlphabetError, DecodingError, VerificationError};
+use crate::{decode, encode, verify};
+
+/// A set of 60 distinct ASCII chars used to represent the values of the G60 encoding,
+/// sorted by value.
+///
+/// The encoding and decoding reuse the G60 group math, so only the chars change.
+///
+/// ```rust
+/// # use g60::Alphabet;
+/// const REVERSED: Alphabet =
+///     Alphabet::new_const(b"zyxwvutsrqponmlkjihgfedcbaZYXWVUTSRQPNMLKJHGFEDCBA9876543210");
+///
+/// let encoded = REVERSED.encode(b"Hello, world!");
+/// assert_eq!(REVERSED.decode(&encoded).unwrap(), b"Hello, world!");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Alphabet {
+    chars: [u8; 60],
+    values: [u8; 128],
+}
+
+impl Alphabet {
+    /// The standard G60 alphabet: all letters and digits except for capital I and O.
+    pub const G60: Alphabet = Alphabet::new_const(ENCODED_TO_UTF8_MAP);
+
+    /// The G60 alphabet replacing the lowercase `l`, easily mistaken for `1`, with `_`.
+    /// The chars keep the ASCII order, so the encoding is still monotonic.
+    pub const NO_AMBIGUITY: Alphabet =
+        Alphabet::new_const(b"0123456789ABCDEFGHJKLMNPQRSTUVWXYZ_abcdefghijkmnopqrstuvwxyz");
+
+    /// An alphabet with all the uppercase letters, excluding instead the lowercase `l` and
+    /// `o`. Useful where uppercase text is preferred. The chars keep the ASCII order, so
+    /// the encoding is still monotonic.
+    pub const UPPERCASE_BIASED: Alphabet =
+        Alphabet::new_const(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz");
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds an alphabet from its 60 chars sorted by value.
+    ///
+    /// # Errors
+    /// An error will be thrown if `chars` does not have exactly 60 distinct ASCII chars.
+    pub fn new(chars: &str) -> Result<Alphabet, AlphabetError> {
+        Self::build(chars.as_bytes())
+    }
+
+    /// Builds an alphabet from its 60 chars sorted by value in a const context.
+    ///
+    /// # Panics
+    /// Panics, or fails to compile when used in a const, if `chars` has any repeated or
+    /// non-ASCII char.
+    pub const fn new_const(chars: &[u8; 60]) -> Alphabet {
+        match Self::build(chars) {
+            Ok(v) => v,
+            Err(_) => panic!("The alphabet must have 60 distinct ASCII chars"),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the chars of the alphabet sorted by value.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the chars are checked to be ASCII.
+        unsafe { std::str::from_utf8_unchecked(&self.chars) }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Encodes a list of bytes using this alphabet.
+    pub fn encode(&self, content: &[u8]) -> String {
+        let mut encoded = encode(content).into_bytes();
+        for byte in encoded.iter_mut() {
+            *byte = self.chars[UTF8_TO_ENCODED_MAP[*byte as usize] as usize];
+        }
+
+        // SAFETY: the chars are checked to be ASCII.
+        unsafe { String::from_utf8_unchecked(encoded) }
+    }
+
+    /// Decodes a string encoded using this alphabet.
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical encoded string.
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>, DecodingError> {
+        decode(&self.translate_to_g60(encoded)?)
+    }
+
+    /// Verifies a string encoded using this alphabet.
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical encoded string.
+    pub fn verify(&self, encoded: &str) -> Result<(), VerificationError> {
+        verify(&self.translate_to_g60(encoded)?)
+    }
+
+    /// Converts a string encoded using this alphabet into the same string encoded using
+    /// `alphabet`, without decoding it.
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical encoded string.
+    pub fn transcode(
+        &self,
+        encoded: &str,
+        alphabet: &Alphabet,
+    ) -> Result<String, VerificationError> {
+        let translated = self.translate_to_g60(encoded)?;
+        verify(&translated)?;
+
+        let mut result = translated.into_bytes();
+        for byte in result.iter_mut() {
+            *byte = alphabet.chars[UTF8_TO_ENCODED_MAP[*byte as usize] as usize];
+        }
+
+        // SAFETY: the chars are checked to be ASCII.
+        Ok(unsafe { String::from_utf8_unchecked(result) })
+    }
+
+    // AUX METHODS ------------------------------------------------------------
+
+    const fn build(chars: &[u8]) -> Result<Alphabet, AlphabetError> {
+        if chars.len() != 60 {
+            return Err(AlphabetError::InvalidLength {
+                length: chars.len(),
+            });
+        }
+
+        let mut alphabet = Alphabet {
+            chars: [0; 60],
+            values: [255; 128],
+        };
+
+        let mut index = 0;
+        while index < 60 {
+            let byte = chars[index];
+
+            if !byte.is_ascii() {
+                return Err(AlphabetError::InvalidByte { index, byte });
+            }
+
+            if alphabet.values[byte as usize] != 255 {
+                return Err(AlphabetError::RepeatedByte { index, byte });
+            }
+
+            alphabet.chars[index] = byte;
+            alphabet.values[byte as usize] = index as u8;
+            index += 1;
+        }
+
+        Ok(alphabet)
+    }
+
+    /// Translates `encoded` into the standard G60 alphabet.
+    fn translate_to_g60(&self, encoded: &str) -> Result<String, VerificationError> {
+        let mut translated = Vec::with_capacity(encoded.len());
+
+        for (index, byte) in encoded.bytes().enumerate() {
+            let value = *self.values.get(byte as usize).unwrap_or(&255);
+
+            if value == 255 {
+                return Err(VerificationError::InvalidByte { index, byte });
+            }
+
+            translated.push(ENCODED_TO_UTF8_MAP[value as usize]);
+        }
+
+        // SAFETY: the G60 alphabet is ASCII.
+        Ok(unsafe { String::from_utf8_unchecked(translated) })
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::G60
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_g60_alphabet() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let encoded = encode(&bytes);
+
+        assert_eq!(
+            Alphabet::G60.as_str(),
+            std::str::from_utf8(ENCODED_TO_UTF8_MAP).unwrap()
+        );
+        assert_eq!(Alphabet::G60.encode(&bytes), encoded);
+        assert_eq!(Alphabet::G60.decode(&encoded), Ok(bytes));
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        let alphabet =
+            Alphabet::new("zyxwvutsrqponmlkjihgfedcbaZYXWVUTSRQPNMLKJHGFEDCBA9876543210")
+                .expect("The alphabet must be valid");
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+
+        let encoded = alphabet.encode(&bytes);
+        assert_eq!(alphabet.verify(&encoded), Ok(()));
+        assert_eq!(alphabet.decode(&encoded), Ok(bytes));
+
+        assert_eq!(
+            alphabet.encode(b"Hello, world!"),
+            "j6vnjkHiMJ0a0Gnkyt",
+            "Incorrect encoding"
+        );
+
+        for (test, index, byte) in [
+            ("j6vnjkHiMJ0a0GnkyI", 17, b'I'),
+            ("j6vnjkHi\u{e9}", 8, 0xC3),
+        ] {
+            assert_eq!(
+                alphabet.verify(test),
+                Err(VerificationError::InvalidByte { index, byte }),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+
+    #[test]
+    fn test_presets() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let alphabets = [
+            Alphabet::G60,
+            Alphabet::NO_AMBIGUITY,
+            Alphabet::UPPERCASE_BIASED,
+        ];
+
+        for from in &alphabets {
+            assert!(
+                from.chars.windows(2).all(|v| v[0] < v[1]),
+                "Unsorted alphabet '{}'",
+                from.as_str()
+            );
+
+            let encoded = from.encode(&bytes);
+            assert_eq!(
+                from.decode(&encoded),
+                Ok(bytes.clone()),
+                "Incorrect for '{}'",
+                from.as_str()
+            );
+
+            for to in &alphabets {
+                assert_eq!(
+                    from.transcode(&encoded, to),
+                    Ok(to.encode(&bytes)),
+                    "Incorrect from '{}' to '{}'",
+                    from.as_str(),
+                    to.as_str()
+                );
+            }
+        }
+
+        let test = "Gt4CGFiHehzRzjCF17";
+        assert_eq!(
+            Alphabet::G60.transcode(test, &Alphabet::NO_AMBIGUITY),
+            Err(VerificationError::NotCanonical),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_invalid_alphabets() {
+        let valid = "0123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+        for (test, error) in [
+            (&valid[1..], AlphabetError::InvalidLength { length: 59 }),
+            (
+                "0123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijklmnopqrstuvwxy0",
+                AlphabetError::RepeatedByte {
+                    index: 59,
+                    byte: b'0',
+                },
+            ),
+        ] {
+            assert_eq!(Alphabet::new(test), Err(error), "Incorrect for '{}'", test);
+        }
+
+        let mut test = valid.as_bytes().to_vec();
+        test[58] = 0xC3;
+        test[59] = 0xA9;
+        assert_eq!(
+            Alphabet::new(std::str::from_utf8(&test).unwrap()),
+            Err(AlphabetError::InvalidByte {
+                index: 58,
+                byte: 0xC3,
+            }),
+            "Incorrect for non-ASCII chars"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::canonicalization::canonicalize_last_group;
+use crate::decoding::compute_chunk;
+use crate::errors::VerificationError;
+use crate::{decode_with_canonicality, G60String};
+
+/// An owned G60 encoded string that is guaranteed to be valid but may not be canonical,
+/// i.e. the unused bits of its last incomplete group may not be zero.
+///
+/// [G60String] is its canonical counterpart, so APIs that need the canonical form, like
+/// writers of database keys, can require it at compile time by accepting a [G60String],
+/// while the values received from lenient sources are kept as [AnyValidG60String] until
+/// they are [canonicalized](AnyValidG60String::canonicalize).
+///
+/// ```rust
+/// # use g60::AnyValidG60String;
+/// let encoded = AnyValidG60String::new("0f".to_string()).unwrap();
+///
+/// assert!(!encoded.is_canonical());
+/// assert_eq!(encoded.canonicalize().as_str(), "0U");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct AnyValidG60String(String);
+
+impl AnyValidG60String {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Verifies `encoded` is valid, accepting non-canonical strings, and wraps it into an
+    /// [AnyValidG60String].
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid G60 encoded string.
+    pub fn new(encoded: String) -> Result<AnyValidG60String, VerificationError> {
+        let bytes = encoded.as_bytes();
+        let complete_groups_length = bytes.len() / 11 * 11;
+
+        for (i, chunk) in bytes[..complete_groups_length].chunks_exact(11).enumerate() {
+            compute_chunk(i * 11, chunk)?;
+        }
+
+        canonicalize_last_group(complete_groups_length, &bytes[complete_groups_length..])?;
+
+        Ok(AnyValidG60String(encoded))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the encoded string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the encoded string is canonical.
+    pub fn is_canonical(&self) -> bool {
+        let bytes = self.0.as_bytes();
+        let last_group = &bytes[bytes.len() / 11 * 11..];
+        let canonical = canonicalize_last_group(0, last_group).unwrap();
+
+        canonical[..last_group.len()] == *last_group
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Decodes the encoded string.
+    pub fn decode(&self) -> Vec<u8> {
+        decode_with_canonicality(&self.0).unwrap().0
+    }
+
+    /// Converts the encoded string into its canonical form, rewriting only the last
+    /// incomplete group in place.
+    pub fn canonicalize(self) -> G60String {
+        let mut encoded = self.0.into_bytes();
+        let complete_groups_length = encoded.len() / 11 * 11;
+        let last_group = &mut encoded[complete_groups_length..];
+        let canonical = canonicalize_last_group(0, last_group).unwrap();
+        let last_group_length = last_group.len();
+        last_group.copy_from_slice(&canonical[..last_group_length]);
+
+        // SAFETY: the canonical form of a valid string is a valid canonical ASCII string.
+        unsafe { G60String::new_unchecked(String::from_utf8_unchecked(encoded)) }
+    }
+
+    /// Returns the inner encoded string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Debug for AnyValidG60String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for AnyValidG60String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl AsRef<str> for AnyValidG60String {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<G60String> for AnyValidG60String {
+    fn from(encoded: G60String) -> Self {
+        AnyValidG60String(encoded.into_string())
+    }
+}
+
+impl From<AnyValidG60String> for G60String {
+    fn from(encoded: AnyValidG60String) -> Self {
+        encoded.canonicalize()
+    }
+}
+
+impl TryFrom<String> for AnyValidG60String {
+    type Error = VerificationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        AnyValidG60String::new(value)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        for (test, canonical) in [
+            ("", ""),
+            ("Gt4CGFiHehzRzjCF16", "Gt4CGFiHehzRzjCF16"),
+            ("Gt4CGFiHehzRzjCF17", "Gt4CGFiHehzRzjCF16"),
+            ("0f", "0U"),
+        ] {
+            let encoded =
+                AnyValidG60String::new(test.to_string()).expect("The verification must succeed");
+
+            assert_eq!(encoded.as_str(), test);
+            assert_eq!(
+                encoded.is_canonical(),
+                test == canonical,
+                "Incorrect for '{}'",
+                test
+            );
+            assert_eq!(
+                encoded.decode(),
+                G60String::new(canonical.to_string()).unwrap().decode(),
+                "Incorrect for '{}'",
+                test
+            );
+            assert_eq!(
+                encoded.canonicalize().as_str(),
+                canonical,
+                "Incorrect for '{}'",
+                test
+            );
+        }
+
+        for (test, error) in [
+            ("Gt4CGFiHehzRzjCF16z", VerificationError::InvalidLength),
+            (
+                "Gt4CGFiHehzRzjCF1O",
+                VerificationError::InvalidByte {
+                    index: 17,
+                    byte: b'O',
+                },
+            ),
+            ("zzzzzzzzzzz", VerificationError::NotCanonical),
+        ] {
+            assert_eq!(
+                AnyValidG60String::new(test.to_string()),
+                Err(error),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let encoded = G60String::encode(b"Hello, world!");
+        let any_valid = AnyValidG60String::from(encoded.clone());
+
+        assert!(any_valid.is_canonical());
+        assert_eq!(G60String::from(any_valid), encoded);
+    }
+}
@@ -0,0 +1,188 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{G60Str, G60String};
+
+/// A shared G60 encoded string that is guaranteed to be valid and canonical. Cloning it
+/// only increments a reference count, so many copies of the same identifier share one
+/// allocation. See [G60Interner] to deduplicate equal strings.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ArcG60String(Arc<str>);
+
+impl ArcG60String {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Borrows the encoded string as a [G60Str].
+    pub fn as_g60_str(&self) -> &G60Str {
+        // SAFETY: `ArcG60String` is only built from a `G60String` or a `G60Str`, so it is
+        // valid and canonical.
+        unsafe { G60Str::new_unchecked(&self.0) }
+    }
+
+    /// Returns whether `this` and `other` share the same allocation.
+    pub fn ptr_eq(this: &ArcG60String, other: &ArcG60String) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl Debug for ArcG60String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for ArcG60String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Deref for ArcG60String {
+    type Target = G60Str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_g60_str()
+    }
+}
+
+impl Borrow<G60Str> for ArcG60String {
+    fn borrow(&self) -> &G60Str {
+        self.as_g60_str()
+    }
+}
+
+impl AsRef<G60Str> for ArcG60String {
+    fn as_ref(&self) -> &G60Str {
+        self.as_g60_str()
+    }
+}
+
+impl AsRef<str> for ArcG60String {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<G60String> for ArcG60String {
+    fn from(encoded: G60String) -> Self {
+        ArcG60String(Arc::from(encoded.into_string()))
+    }
+}
+
+impl From<&G60Str> for ArcG60String {
+    fn from(encoded: &G60Str) -> Self {
+        ArcG60String(Arc::from(encoded.as_str()))
+    }
+}
+
+impl From<&ArcG60String> for G60String {
+    fn from(encoded: &ArcG60String) -> Self {
+        encoded.as_g60_str().to_owned()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A set of [ArcG60String]s that returns the same shared allocation for equal encoded
+/// strings, for workloads that hold many duplicates of the same identifiers.
+///
+/// ```rust
+/// # use g60::{ArcG60String, G60Interner, G60String};
+/// let mut interner = G60Interner::new();
+/// let a = interner.intern(&G60String::encode(b"label"));
+/// let b = interner.intern(&G60String::encode(b"label"));
+///
+/// assert!(ArcG60String::ptr_eq(&a, &b));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct G60Interner {
+    strings: HashSet<ArcG60String>,
+}
+
+impl G60Interner {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds an empty interner.
+    pub fn new() -> G60Interner {
+        Self::default()
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the number of distinct interned strings.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns whether there is no interned string.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Returns the shared string equal to `encoded`, allocating it only the first time.
+    pub fn intern(&mut self, encoded: &G60Str) -> ArcG60String {
+        if let Some(interned) = self.strings.get(encoded) {
+            return interned.clone();
+        }
+
+        let interned = ArcG60String::from(encoded);
+        self.strings.insert(interned.clone());
+
+        interned
+    }
+
+    /// Removes the strings that are not referenced outside the interner anymore.
+    pub fn shrink(&mut self) {
+        self.strings.retain(|v| Arc::strong_count(&v.0) > 1);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arc_g60_string() {
+        let encoded = G60String::encode(b"Hello, world!");
+        let shared = ArcG60String::from(encoded.clone());
+        let copy = shared.clone();
+
+        assert!(ArcG60String::ptr_eq(&shared, &copy));
+        assert_eq!(shared.as_g60_str(), encoded.as_g60_str());
+        assert_eq!(shared.decode(), b"Hello, world!");
+        assert_eq!(shared.to_string(), "Gt4CGFiHehzRzjCF16");
+        assert_eq!(G60String::from(&shared), encoded);
+    }
+
+    #[test]
+    fn test_interner() {
+        let mut interner = G60Interner::new();
+        let a = interner.intern(&G60String::encode(b"a"));
+        let b = interner.intern(&G60String::encode(b"b"));
+        let a2 = interner.intern(&G60String::encode(b"a"));
+
+        assert_eq!(interner.len(), 2);
+        assert!(ArcG60String::ptr_eq(&a, &a2));
+        assert!(!ArcG60String::ptr_eq(&a, &b));
+
+        drop(b);
+        interner.shrink();
+        assert_eq!(interner.len(), 1);
+        assert!(ArcG60String::ptr_eq(
+            &a,
+            &interner.intern(&G60String::encode(b"a"))
+        ));
+    }
+}
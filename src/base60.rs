@@ -0,0 +1,256 @@
+//! A positional base-60 encoding that, unlike G60, treats the whole content as a single
+//! big-endian integer, like base58 or base36 do. Intended for interoperability with
+//! systems that expect a pure base conversion. It uses the G60 alphabet.
+//!
+//! Each leading zero byte is represented by a leading `0` char, so the leading zeros are
+//! preserved in a round trip.
+//!
+//! The conversion is quadratic in the length of the content, so prefer G60 for anything
+//! but short values.
+//!
+//! ```rust
+//! let encoded = g60::base60::encode(&[0, 1, 0]);
+//!
+//! assert_eq!(encoded, "04G");
+//! assert_eq!(g60::base60::decode(&encoded).unwrap(), [0, 1, 0]);
+//! ```
+
+use crate::constants::{ENCODED_TO_UTF8_MAP, UTF8_TO_ENCODED_MAP};
+use crate::errors::{DecodingError, EncodingError, VerificationError};
+
+/// Encodes a list of bytes as a base-60 number.
+pub fn encode(content: &[u8]) -> String {
+    let leading_zeros = content.iter().take_while(|v| **v == 0).count();
+
+    // Base-60 digits in little-endian order.
+    let mut digits: Vec<u8> = Vec::with_capacity(content.len() * 136 / 100 + 1);
+    for byte in &content[leading_zeros..] {
+        let mut carry = *byte as usize;
+
+        for digit in digits.iter_mut() {
+            carry += (*digit as usize) << 8;
+            *digit = (carry % 60) as u8;
+            carry /= 60;
+        }
+
+        while carry != 0 {
+            digits.push((carry % 60) as u8);
+            carry /= 60;
+        }
+    }
+
+    let mut result = String::with_capacity(leading_zeros + digits.len());
+    result.extend(std::iter::repeat_n('0', leading_zeros));
+    result.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|v| ENCODED_TO_UTF8_MAP[*v as usize] as char),
+    );
+
+    result
+}
+
+/// Decodes a base-60 number into a list of bytes.
+///
+/// # Errors
+/// An error will be thrown if `encoded` contains any char outside the G60 alphabet.
+pub fn decode(encoded: &str) -> Result<Vec<u8>, VerificationError> {
+    let bytes = encoded.as_bytes();
+    let leading_zeros = bytes.iter().take_while(|v| **v == b'0').count();
+
+    // Bytes in little-endian order.
+    let mut result: Vec<u8> = Vec::with_capacity(bytes.len() * 74 / 100 + 1);
+    for (index, byte) in bytes.iter().enumerate().skip(leading_zeros) {
+        let value = *UTF8_TO_ENCODED_MAP.get(*byte as usize).unwrap_or(&255);
+        if value == 255 {
+            return Err(VerificationError::InvalidByte { index, byte: *byte });
+        }
+
+        let mut carry = value as usize;
+        for decoded in result.iter_mut() {
+            carry += *decoded as usize * 60;
+            *decoded = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry != 0 {
+            result.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    result.resize(result.len() + leading_zeros, 0);
+    result.reverse();
+
+    Ok(result)
+}
+
+/// Encodes a number as a base-60 number of exactly `width` chars, left-padded with `0`
+/// chars, so numbers of different magnitudes encoded with the same width sort in the same
+/// order as strings.
+///
+/// ```rust
+/// assert_eq!(g60::base60::encode_u64_padded(61, 4).unwrap(), "0011");
+/// assert_eq!(g60::base60::decode_u64_padded("0011").unwrap(), 61);
+/// ```
+///
+/// # Errors
+/// An error will be thrown if `value` needs more than `width` chars.
+pub fn encode_u64_padded(value: u64, width: usize) -> Result<String, EncodingError> {
+    // Base-60 digits in little-endian order.
+    let mut digits = Vec::with_capacity(11);
+    let mut rest = value;
+    while rest != 0 {
+        digits.push((rest % 60) as u8);
+        rest /= 60;
+    }
+
+    if digits.len() > width {
+        return Err(EncodingError::WidthExceeded {
+            width,
+            required: digits.len(),
+        });
+    }
+
+    let mut result = String::with_capacity(width);
+    result.extend(std::iter::repeat_n('0', width - digits.len()));
+    result.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|v| ENCODED_TO_UTF8_MAP[*v as usize] as char),
+    );
+
+    Ok(result)
+}
+
+/// Decodes a number encoded with [encode_u64_padded]. The leading `0` chars are ignored,
+/// so any width is accepted.
+///
+/// # Errors
+/// An error will be thrown in the following cases:
+/// - if `encoded` contains any char outside the G60 alphabet.
+/// - if the number does not fit in a `u64`.
+pub fn decode_u64_padded(encoded: &str) -> Result<u64, DecodingError> {
+    let mut result = 0u64;
+
+    for (index, byte) in encoded.bytes().enumerate() {
+        let value = *UTF8_TO_ENCODED_MAP.get(byte as usize).unwrap_or(&255);
+        if value == 255 {
+            return Err(VerificationError::InvalidByte { index, byte }.into());
+        }
+
+        result = result
+            .checked_mul(60)
+            .and_then(|v| v.checked_add(value as u64))
+            .ok_or(DecodingError::OutOfRange)?;
+    }
+
+    Ok(result)
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        for (test, expected) in [
+            (&[][..], ""),
+            (&[0], "0"),
+            (&[0, 0], "00"),
+            (&[59], "z"),
+            (&[60], "10"),
+            (&[1, 0], "4G"),
+            (&[0, 1, 0], "04G"),
+            (&[255, 255], "JCF"),
+        ] {
+            assert_eq!(encode(test), expected, "Incorrect for {:?}", test);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for length in 0..40 {
+            for leading_zeros in [0, 1, 3] {
+                let mut bytes = vec![0; leading_zeros];
+                bytes.extend((0..length).map(|v| (v * 37 + 11) as u8));
+
+                let encoded = encode(&bytes);
+                assert_eq!(decode(&encoded), Ok(bytes), "Incorrect for '{}'", encoded);
+            }
+        }
+    }
+
+    #[test]
+    fn test_u64_padded() {
+        for (value, width, expected) in [
+            (0, 0, ""),
+            (0, 3, "000"),
+            (59, 1, "z"),
+            (61, 4, "0011"),
+            (u64::MAX, 11, "WWT953qgX0F"),
+        ] {
+            assert_eq!(
+                encode_u64_padded(value, width),
+                Ok(expected.to_string()),
+                "Incorrect for {}",
+                value
+            );
+            assert_eq!(
+                decode_u64_padded(expected),
+                Ok(value),
+                "Incorrect for {}",
+                value
+            );
+        }
+
+        let mut previous = encode_u64_padded(0, 5).unwrap();
+        for value in (1..60u64.pow(5)).step_by(997) {
+            let encoded = encode_u64_padded(value, 5).unwrap();
+
+            assert!(encoded > previous, "Incorrect for {}", value);
+            previous = encoded;
+        }
+
+        assert_eq!(
+            encode_u64_padded(60, 1),
+            Err(EncodingError::WidthExceeded {
+                width: 1,
+                required: 2,
+            })
+        );
+        assert_eq!(
+            decode_u64_padded("zzzzzzzzzzz"),
+            Err(DecodingError::OutOfRange)
+        );
+        assert_eq!(
+            decode_u64_padded("0O"),
+            Err(DecodingError::Verification(
+                VerificationError::InvalidByte {
+                    index: 1,
+                    byte: b'O',
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let test = "04GO";
+        assert_eq!(
+            decode(test),
+            Err(VerificationError::InvalidByte {
+                index: 3,
+                byte: b'O',
+            }),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::errors::DecodingError;
+use crate::{decode, encode};
+
+/// Encodes the first `bit_length` bits of `content` into a G60 encoding format, reading
+/// the bits of each byte from the most significant one.
+///
+/// The bits are followed by a single `1` bit and as many `0` bits as needed to complete a
+/// byte, so the bit length can be recovered by [decode_bits] at the cost of at most a byte.
+///
+/// # Panics
+/// Panics if `content` has less than `bit_length` bits.
+pub fn encode_bits(content: &[u8], bit_length: usize) -> String {
+    assert!(
+        bit_length <= content.len() * 8,
+        "The content has less than {} bits",
+        bit_length
+    );
+
+    let (complete_bytes, last_bits) = (bit_length >> 3, bit_length & 0x7);
+    let mut padded = Vec::with_capacity(complete_bytes + 1);
+    padded.extend_from_slice(&content[..complete_bytes]);
+
+    let last_byte = content.get(complete_bytes).copied().unwrap_or(0);
+    let mask = !(0xFFu8 >> last_bits);
+    padded.push((last_byte & mask) | (0x80 >> last_bits));
+
+    encode(&padded)
+}
+
+/// Decodes a G60 encoded string produced by [encode_bits], returning the bits and their
+/// length. The unused bits of the last byte are zero.
+///
+/// # Errors
+/// An error will be thrown if `encoded` is not a valid canonical G60 encoded string or it
+/// does not end with the padding of [encode_bits].
+pub fn decode_bits(encoded: &str) -> Result<(Vec<u8>, usize), DecodingError> {
+    let mut decoded = decode(encoded)?;
+
+    let last_byte = match decoded.pop() {
+        Some(v) if v != 0 => v,
+        _ => return Err(DecodingError::InvalidBitPadding),
+    };
+
+    let last_bits = 7 - last_byte.trailing_zeros() as usize;
+    let bit_length = (decoded.len() << 3) + last_bits;
+
+    if last_bits != 0 {
+        decoded.push(last_byte & !(0xFFu8 >> last_bits));
+    }
+
+    Ok((decoded, bit_length))
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_bits() {
+        assert_eq!(encode_bits(&[], 0), encode(&[0x80]));
+        assert_eq!(encode_bits(&[0b1010_1111], 3), encode(&[0b1011_0000]));
+        assert_eq!(encode_bits(&[0xFF], 8), encode(&[0xFF, 0x80]));
+        assert_eq!(encode_bits(&[0xFF, 0xFF], 9), encode(&[0xFF, 0b1100_0000]));
+    }
+
+    #[test]
+    fn test_decode_bits() {
+        let content: Vec<u8> = (0..=255u8).rev().take(20).collect();
+
+        for bit_length in 0..=content.len() * 8 {
+            let encoded = encode_bits(&content, bit_length);
+            let (decoded, decoded_bit_length) =
+                decode_bits(&encoded).expect("The decoding must succeed");
+
+            let mut expected = content[..bit_length.div_ceil(8)].to_vec();
+            if bit_length % 8 != 0 {
+                *expected.last_mut().unwrap() &= !(0xFFu8 >> (bit_length % 8));
+            }
+
+            assert_eq!(decoded_bit_length, bit_length, "Incorrect for {bit_length}");
+            assert_eq!(decoded, expected, "Incorrect for {bit_length}");
+        }
+    }
+
+    #[test]
+    fn test_decode_bits_errors() {
+        for test in ["", "00"] {
+            let error = decode_bits(test).expect_err("The decoding cannot succeed");
+
+            assert_eq!(
+                error,
+                DecodingError::InvalidBitPadding,
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+}
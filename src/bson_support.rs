@@ -0,0 +1,109 @@
+use bson::spec::BinarySubtype;
+use bson::{Binary, Bson};
+
+use crate::{G60Str, G60String};
+
+impl From<&G60Str> for Bson {
+    /// Converts the encoded string into a BSON string.
+    fn from(encoded: &G60Str) -> Self {
+        Bson::String(encoded.as_str().to_owned())
+    }
+}
+
+impl From<G60String> for Bson {
+    /// Converts the encoded string into a BSON string.
+    ///
+    /// The serde implementation of [G60String] stores the decoded bytes in the raw BSON
+    /// serializer used by `bson::to_vec` and the MongoDB driver because it is not
+    /// human-readable, so use `#[serde(with = "g60::serde::string")]` in the fields of
+    /// documents to get the same BSON string whichever API serializes them.
+    fn from(encoded: G60String) -> Self {
+        Bson::String(encoded.into_string())
+    }
+}
+
+impl From<&G60Str> for Binary {
+    /// Decodes the encoded string into a generic BSON binary, to store the content in
+    /// documents with the G60 encoding used only at the API edges.
+    fn from(encoded: &G60Str) -> Self {
+        Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: encoded.decode(),
+        }
+    }
+}
+
+impl From<&G60String> for Binary {
+    fn from(encoded: &G60String) -> Self {
+        Binary::from(encoded.as_g60_str())
+    }
+}
+
+impl From<&Binary> for G60String {
+    /// Encodes the bytes of a BSON binary of any subtype.
+    fn from(binary: &Binary) -> Self {
+        G60String::encode(&binary.bytes)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bson() {
+        let encoded = G60String::encode(b"Hello, world!");
+
+        assert_eq!(
+            Bson::from(encoded.as_g60_str()),
+            Bson::String("Gt4CGFiHehzRzjCF16".to_string())
+        );
+        assert_eq!(
+            Bson::from(encoded),
+            Bson::String("Gt4CGFiHehzRzjCF16".to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_string() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+        struct Document {
+            #[serde(with = "crate::serde::string")]
+            id: G60String,
+        }
+
+        let document = Document {
+            id: G60String::encode(b"Hello, world!"),
+        };
+        let expected = Bson::String("Gt4CGFiHehzRzjCF16".to_string());
+
+        let raw = bson::to_raw_document_buf(&document).unwrap();
+        let id = raw.get("id").unwrap().unwrap().to_raw_bson();
+        assert_eq!(Bson::try_from(id).unwrap(), expected);
+        assert_eq!(
+            bson::from_slice::<Document>(raw.as_bytes()).unwrap(),
+            document
+        );
+
+        let parsed = bson::to_document(&document).unwrap();
+        assert_eq!(parsed.get("id"), Some(&expected));
+        assert_eq!(bson::from_document::<Document>(parsed).unwrap(), document);
+    }
+
+    #[test]
+    fn test_binary() {
+        let encoded = G60String::encode(b"Hello, world!");
+        let binary = Binary::from(&encoded);
+
+        assert_eq!(binary.subtype, BinarySubtype::Generic);
+        assert_eq!(binary.bytes, b"Hello, world!");
+        assert_eq!(G60String::from(&binary), encoded);
+    }
+}
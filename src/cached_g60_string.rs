@@ -0,0 +1,213 @@
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::OnceLock;
+
+use crate::errors::VerificationError;
+use crate::{G60Str, G60String};
+
+/// A [G60String] that memoizes its decoded content, so repeated reads of the same value
+/// decode it only once. It keeps [G60String] lean for the common case of decoding once.
+///
+/// ```rust
+/// # use g60::{CachedG60String, G60String};
+/// let encoded = CachedG60String::from(G60String::encode(b"Hello, world!"));
+///
+/// assert_eq!(encoded.decode(), b"Hello, world!");
+/// assert_eq!(encoded.as_str(), "Gt4CGFiHehzRzjCF16");
+/// ```
+#[derive(Clone)]
+pub struct CachedG60String {
+    encoded: G60String,
+    decoded: OnceLock<Vec<u8>>,
+}
+
+impl CachedG60String {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Verifies `encoded` and wraps it into a [CachedG60String], caching the content
+    /// decoded during the verification.
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical G60 encoded string.
+    pub fn new(encoded: String) -> Result<CachedG60String, VerificationError> {
+        let (encoded, decoded) = G60String::new_and_decode(encoded)?;
+
+        Ok(CachedG60String {
+            encoded,
+            decoded: OnceLock::from(decoded),
+        })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Borrows the encoded string as a [G60Str].
+    pub fn as_g60_str(&self) -> &G60Str {
+        self.encoded.as_g60_str()
+    }
+
+    /// Returns the decoded content, decoding it only on the first call. It shadows
+    /// [G60Str::decode], which would decode the string again on every call.
+    pub fn decode(&self) -> &[u8] {
+        self.decoded.get_or_init(|| self.encoded.decode())
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Returns the inner [G60String], dropping the cached content.
+    pub fn into_inner(mut self) -> G60String {
+        std::mem::replace(&mut self.encoded, G60String::encode(b""))
+    }
+}
+
+impl Debug for CachedG60String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.encoded, f)
+    }
+}
+
+impl Display for CachedG60String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.encoded, f)
+    }
+}
+
+impl PartialEq for CachedG60String {
+    fn eq(&self, other: &Self) -> bool {
+        self.encoded == other.encoded
+    }
+}
+
+impl Eq for CachedG60String {}
+
+impl Hash for CachedG60String {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encoded.hash(state)
+    }
+}
+
+impl Deref for CachedG60String {
+    type Target = G60Str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_g60_str()
+    }
+}
+
+impl Borrow<G60Str> for CachedG60String {
+    fn borrow(&self) -> &G60Str {
+        self.as_g60_str()
+    }
+}
+
+impl AsRef<G60Str> for CachedG60String {
+    fn as_ref(&self) -> &G60Str {
+        self.as_g60_str()
+    }
+}
+
+impl AsRef<str> for CachedG60String {
+    fn as_ref(&self) -> &str {
+        self.encoded.as_str()
+    }
+}
+
+impl From<G60String> for CachedG60String {
+    fn from(encoded: G60String) -> Self {
+        CachedG60String {
+            encoded,
+            decoded: OnceLock::new(),
+        }
+    }
+}
+
+impl From<CachedG60String> for G60String {
+    fn from(encoded: CachedG60String) -> Self {
+        encoded.into_inner()
+    }
+}
+
+impl TryFrom<String> for CachedG60String {
+    type Error = VerificationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        CachedG60String::new(value)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for CachedG60String {
+    /// Zeroes the encoded string and the cached content, leaving both empty, which is
+    /// still valid.
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.encoded);
+
+        if let Some(decoded) = self.decoded.get_mut() {
+            zeroize::Zeroize::zeroize(decoded);
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for CachedG60String {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for CachedG60String {}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let encoded = CachedG60String::from(G60String::encode(b"Hello, world!"));
+        assert!(encoded.decoded.get().is_none());
+
+        let first = encoded.decode().as_ptr();
+
+        assert_eq!(encoded.decode(), b"Hello, world!");
+        assert_eq!(encoded.decode().as_ptr(), first);
+        assert_eq!(encoded.as_g60_str().decode(), b"Hello, world!");
+    }
+
+    #[test]
+    fn test_new() {
+        let encoded = CachedG60String::new("Gt4CGFiHehzRzjCF16".to_string())
+            .expect("The verification must succeed");
+
+        assert_eq!(
+            encoded.decoded.get().map(Vec::as_slice),
+            Some(&b"Hello, world!"[..])
+        );
+        assert_eq!(
+            CachedG60String::new("0f".to_string()),
+            Err(VerificationError::NotCanonical)
+        );
+        assert_eq!(
+            G60String::from(encoded),
+            G60String::encode(b"Hello, world!")
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut encoded = CachedG60String::from(G60String::encode(b"Hello, world!"));
+        assert_eq!(encoded.decode(), b"Hello, world!");
+        encoded.zeroize();
+
+        assert_eq!(encoded.as_str(), "");
+        assert_eq!(encoded.decode(), b"");
+    }
+}
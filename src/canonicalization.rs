@@ -0,0 +1,204 @@
+use std::io::{ErrorKind, Read, Write};
+
+use crate::constants::BLOCK_GROUPS;
+use crate::decoding::{compute_chunk, compute_chunk_fields, compute_decoded_size};
+use crate::encoding;
+use crate::errors::{CanonicalizationError, VerificationError};
+
+/// Size of the working buffer used to canonicalize, a multiple of the group size.
+const BUFFER_SIZE: usize = BLOCK_GROUPS * 11 * 16;
+
+/// Reads a G60 encoded string from `reader` and writes its canonical form into `writer`,
+/// returning the number of elements written.
+///
+/// An encoded string is not canonical when the unused bits of its last incomplete group
+/// are not zero. Canonicalizing clears them, so the result decodes to the same bytes the
+/// non-canonical string represents. Any size is processed with a fixed working buffer.
+///
+/// # Errors
+/// An error will be thrown in the following cases:
+/// - if the reading or writing process fails.
+/// - if the read string is not a valid G60 encoded string, with indexes relative to the
+///   whole string.
+pub fn canonicalize_reader_to_writer<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<usize, CanonicalizationError> {
+    let mut buffer = [0; BUFFER_SIZE];
+    let mut buffer_length = 0;
+    let mut chunk_index = 0;
+
+    loop {
+        let read = match reader.read(&mut buffer[buffer_length..]) {
+            Ok(0) => break,
+            Ok(v) => v,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(CanonicalizationError::ReadingError(e.kind())),
+        };
+        buffer_length += read;
+
+        // Complete groups are always canonical if valid.
+        let complete_groups_length = buffer_length / 11 * 11;
+        for chunk in buffer[..complete_groups_length].chunks_exact(11) {
+            compute_chunk(chunk_index, chunk)?;
+            chunk_index += 11;
+        }
+
+        writer
+            .write_all(&buffer[..complete_groups_length])
+            .map_err(|e| CanonicalizationError::WritingError(e.kind()))?;
+
+        buffer.copy_within(complete_groups_length..buffer_length, 0);
+        buffer_length -= complete_groups_length;
+    }
+
+    // Last incomplete group.
+    let chunk = &buffer[..buffer_length];
+    let canonical = canonicalize_last_group(chunk_index, chunk)?;
+
+    writer
+        .write_all(&canonical[..chunk.len()])
+        .map_err(|e| CanonicalizationError::WritingError(e.kind()))?;
+
+    Ok(chunk_index + chunk.len())
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Computes the canonical form of the last group of an encoded string, which may be
+/// incomplete. Only the first `chunk.len()` elements of the result are meaningful.
+///
+/// # Errors
+/// An error will be thrown if the group has an invalid length, an invalid byte or any
+/// of the fields it actually encodes does not fit in a byte.
+pub(crate) fn canonicalize_last_group(
+    chunk_index: usize,
+    chunk: &[u8],
+) -> Result<[u8; 11], VerificationError> {
+    if chunk.is_empty() {
+        return Ok([0; 11]);
+    }
+
+    if let 1 | 4 | 8 = chunk.len() {
+        return Err(VerificationError::InvalidLength);
+    }
+
+    let fields = compute_chunk_fields(chunk_index, chunk)?;
+    let decoded_length = compute_decoded_size(chunk.len());
+
+    let mut decoded = [0; 8];
+    for (byte, field) in decoded.iter_mut().zip(&fields[..decoded_length]) {
+        *byte = u8::try_from(*field).map_err(|_| VerificationError::NotCanonical)?;
+    }
+
+    Ok(encoding::compute_chunk(&decoded[..decoded_length]))
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::ENCODED_TO_UTF8_MAP;
+    use crate::{decode, encode, verify};
+
+    /// A reader that returns at most 3 elements per read.
+    struct SlowReader<'a>(&'a [u8]);
+
+    impl Read for SlowReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let length = buf.len().min(self.0.len()).min(3);
+            buf[..length].copy_from_slice(&self.0[..length]);
+            self.0 = &self.0[length..];
+
+            Ok(length)
+        }
+    }
+
+    fn canonicalize(encoded: &str) -> Result<String, CanonicalizationError> {
+        let mut result = Vec::new();
+        let written =
+            canonicalize_reader_to_writer(&mut SlowReader(encoded.as_bytes()), &mut result)?;
+
+        assert_eq!(written, result.len(), "Incorrect length for '{}'", encoded);
+
+        Ok(String::from_utf8(result).unwrap())
+    }
+
+    #[test]
+    fn test_canonical_values_are_unchanged() {
+        for length in [0, 1, 7, 8, 13, BUFFER_SIZE / 11 * 8 + 3, BUFFER_SIZE] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = encode(&bytes);
+
+            assert_eq!(
+                canonicalize(&encoded),
+                Ok(encoded),
+                "Incorrect for length {length}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_canonical_values() {
+        for (non_canonical, canonical) in
+            [("0f", "0U"), ("Gt4CGFiHehzRzjCF17", "Gt4CGFiHehzRzjCF16")]
+        {
+            assert!(verify(non_canonical).is_err());
+
+            let result = canonicalize(non_canonical).expect("The canonicalization must succeed");
+
+            assert_eq!(result, canonical, "Incorrect for '{}'", non_canonical);
+            verify(&result).expect("The verification must succeed");
+        }
+
+        // Every 2-chars string that encodes a byte.
+        for i in ENCODED_TO_UTF8_MAP {
+            for j in ENCODED_TO_UTF8_MAP {
+                let encoded = format!("{}{}", *i as char, *j as char);
+
+                if let Ok(result) = canonicalize(&encoded) {
+                    let decoded = decode(&result).expect("The decoding must succeed");
+
+                    assert_eq!(result, encode(&decoded), "Incorrect for '{}'", encoded);
+                    assert!(result <= encoded, "Incorrect for '{}'", encoded);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut encoded = encode(&[7; BUFFER_SIZE]).into_bytes();
+        encoded[BUFFER_SIZE + 5] = b'O';
+        let encoded = String::from_utf8(encoded).unwrap();
+
+        assert_eq!(
+            canonicalize(&encoded),
+            Err(CanonicalizationError::Verification(
+                VerificationError::InvalidByte {
+                    index: BUFFER_SIZE + 5,
+                    byte: b'O',
+                }
+            ))
+        );
+
+        assert_eq!(
+            canonicalize("Gt4CGFiHehzRzjCF16x"),
+            Err(CanonicalizationError::Verification(
+                VerificationError::InvalidLength
+            ))
+        );
+
+        assert_eq!(
+            canonicalize("zz"),
+            Err(CanonicalizationError::Verification(
+                VerificationError::NotCanonical
+            ))
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use crate::constants::{ENCODED_TO_UTF8_MAP, UTF8_TO_ENCODED_MAP};
+use crate::errors::{DecodingError, VerificationError};
+use crate::{decode, encode};
+
+/// CRC-32 (IEEE) lookup table.
+static CRC32_TABLE: [u32; 256] = build_crc32_table();
+
+/// Encodes a list of bytes into a G60 encoding format followed by its CRC-32 checksum,
+/// like base58check does, so [decode_check] can detect corrupted or mistyped strings.
+pub fn encode_check(content: &[u8]) -> String {
+    let mut payload = Vec::with_capacity(content.len() + 4);
+    payload.extend_from_slice(content);
+    payload.extend_from_slice(&crc32(content).to_be_bytes());
+
+    encode(&payload)
+}
+
+/// Decodes a G60 encoded string produced by [encode_check], verifying its checksum.
+///
+/// # Errors
+/// An error will be thrown if `encoded` is not a valid canonical G60 encoded string or the
+/// checksum does not match the content.
+pub fn decode_check(encoded: &str) -> Result<Vec<u8>, DecodingError> {
+    let mut decoded = decode(encoded)?;
+
+    if decoded.len() < 4 {
+        return Err(DecodingError::ChecksumMismatch);
+    }
+
+    let content_length = decoded.len() - 4;
+    let checksum = u32::from_be_bytes(decoded[content_length..].try_into().unwrap());
+
+    if crc32(&decoded[..content_length]) != checksum {
+        return Err(DecodingError::ChecksumMismatch);
+    }
+
+    decoded.truncate(content_length);
+    Ok(decoded)
+}
+
+/// Encodes a list of bytes into a G60 encoding format followed by a check char computed
+/// with the ISO 7064 MOD 61,60 hybrid system, which detects every single char typo and
+/// most adjacent transpositions. Cheaper than [encode_check] for short codes typed by
+/// humans.
+pub fn encode_with_check_char(content: &[u8]) -> String {
+    let mut encoded = encode(content);
+    let check_char = compute_check_char(encoded.as_bytes());
+    encoded.push(check_char as char);
+
+    encoded
+}
+
+/// Decodes a G60 encoded string produced by [encode_with_check_char], verifying its
+/// check char.
+///
+/// # Errors
+/// An error will be thrown if `encoded` is not a valid canonical G60 encoded string
+/// followed by its check char.
+pub fn decode_with_check_char(encoded: &str) -> Result<Vec<u8>, DecodingError> {
+    let bytes = encoded.as_bytes();
+    let Some((check_char, content)) = bytes.split_last() else {
+        return Err(DecodingError::ChecksumMismatch);
+    };
+
+    if *UTF8_TO_ENCODED_MAP
+        .get(*check_char as usize)
+        .unwrap_or(&255)
+        == 255
+    {
+        return Err(DecodingError::Verification(
+            VerificationError::InvalidByte {
+                index: content.len(),
+                byte: *check_char,
+            },
+        ));
+    }
+
+    let decoded = decode(&encoded[..content.len()])?;
+
+    if compute_check_char(content) != *check_char {
+        return Err(DecodingError::ChecksumMismatch);
+    }
+
+    Ok(decoded)
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn crc32(content: &[u8]) -> u32 {
+    !content.iter().fold(!0u32, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Computes the ISO 7064 MOD 61,60 check char of `encoded`, that must only contain chars
+/// of the G60 alphabet.
+fn compute_check_char(encoded: &[u8]) -> u8 {
+    let product = encoded.iter().fold(60, |product, byte| {
+        let value = UTF8_TO_ENCODED_MAP[*byte as usize] as usize;
+        let sum = match (product + value) % 60 {
+            0 => 60,
+            v => v,
+        };
+
+        (sum << 1) % 61
+    });
+
+    ENCODED_TO_UTF8_MAP[(61 - product) % 60]
+}
+
+const fn build_crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::ENCODED_TO_UTF8_MAP;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for length in [0, 1, 4, 13, 100] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = encode_check(&bytes);
+
+            assert_eq!(
+                decode_check(&encoded),
+                Ok(bytes),
+                "Incorrect for '{}'",
+                encoded
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_char_round_trip() {
+        for length in [0, 1, 4, 13, 100] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = encode_with_check_char(&bytes);
+
+            assert_eq!(
+                encoded[..encoded.len() - 1],
+                encode(&bytes),
+                "Incorrect for '{}'",
+                encoded
+            );
+            assert_eq!(
+                decode_with_check_char(&encoded),
+                Ok(bytes),
+                "Incorrect for '{}'",
+                encoded
+            );
+        }
+
+        let test = "";
+        assert_eq!(
+            decode_with_check_char(test),
+            Err(DecodingError::ChecksumMismatch),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_check_char_detects_typos() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(40).collect();
+        let encoded = encode_with_check_char(&bytes).into_bytes();
+        let check_char_index = encoded.len() - 1;
+
+        // Single chars.
+        for index in 0..encoded.len() {
+            for char in ENCODED_TO_UTF8_MAP {
+                let mut test = encoded.clone();
+                if test[index] == *char {
+                    continue;
+                }
+
+                test[index] = *char;
+                let content = &test[..check_char_index];
+
+                assert_ne!(
+                    compute_check_char(content),
+                    test[check_char_index],
+                    "Incorrect for '{}'",
+                    String::from_utf8_lossy(&test)
+                );
+            }
+        }
+
+        // Adjacent transpositions.
+        for index in 0..check_char_index - 1 {
+            let mut test = encoded.clone();
+            if test[index] == test[index + 1] {
+                continue;
+            }
+
+            test.swap(index, index + 1);
+            let content = &test[..check_char_index];
+
+            assert_ne!(
+                compute_check_char(content),
+                test[check_char_index],
+                "Incorrect for '{}'",
+                String::from_utf8_lossy(&test)
+            );
+        }
+    }
+
+    #[test]
+    fn test_typos_are_detected() {
+        let encoded = encode_check(b"Hello, world!");
+
+        for index in 0..encoded.len() {
+            for char in ENCODED_TO_UTF8_MAP {
+                let mut test = encoded.clone().into_bytes();
+                if test[index] == *char {
+                    continue;
+                }
+
+                test[index] = *char;
+                let test = String::from_utf8(test).unwrap();
+
+                // Some typos are detected as non-canonical strings instead.
+                assert!(decode_check(&test).is_err(), "Incorrect for '{}'", test);
+            }
+        }
+
+        let test = "000";
+        assert_eq!(
+            decode_check(test),
+            Err(DecodingError::ChecksumMismatch),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+}
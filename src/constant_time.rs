@@ -0,0 +1,71 @@
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::{G60Str, G60String};
+
+/// Compares two encoded strings in constant time with respect to their content, so secret
+/// values like session tokens or API keys can be checked without timing side channels.
+///
+/// Only the lengths, which are not considered secret, can make the comparison finish
+/// earlier.
+///
+/// ```rust
+/// assert!(g60::constant_time_eq("Gt4CGFiHehzRzjCF16", "Gt4CGFiHehzRzjCF16"));
+/// assert!(!g60::constant_time_eq("Gt4CGFiHehzRzjCF16", "Gt4CGFiHehzRzjCF06"));
+/// ```
+pub fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.as_bytes().ct_eq(b.as_bytes()).into()
+}
+
+impl ConstantTimeEq for G60Str {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+
+impl ConstantTimeEq for G60String {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ct_eq() {
+        let encoded = G60String::encode(b"Hello, world!");
+
+        for (test, expected) in [
+            ("Gt4CGFiHehzRzjCF16", true),
+            ("Gt4CGFiHehzRzjCF06", false),
+            ("Gt4CGFiHehz", false),
+            ("", false),
+        ] {
+            let other = G60String::new(test.to_string()).unwrap();
+
+            assert_eq!(
+                bool::from(encoded.ct_eq(&other)),
+                expected,
+                "Incorrect for '{}'",
+                test
+            );
+            assert_eq!(
+                bool::from(encoded.as_g60_str().ct_eq(other.as_g60_str())),
+                expected,
+                "Incorrect for '{}'",
+                test
+            );
+            assert_eq!(
+                constant_time_eq(encoded.as_str(), test),
+                expected,
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+}
@@ -9,6 +9,60 @@ pub(crate) static UTF8_TO_ENCODED_MAP: &[u8; 123] = &[
     42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59,
 ];
 
+/// Maps every value `60 * c0 + c1` to the pair of chars `c0` and `c1`.
+pub(crate) static ENCODED_PAIR_TO_UTF8_MAP: [[u8; 2]; 3600] = build_encoded_pair_to_utf8_map();
+
+/// Maps every pair of bytes `(b0 << 8) | b1` to `60 * c0 + c1`, where `c0` and `c1` are
+/// their values in [UTF8_TO_ENCODED_MAP], or to `u16::MAX` if any of them is invalid.
+#[cfg(feature = "decoding-pair-table")]
+pub(crate) static UTF8_PAIR_TO_ENCODED_MAP: [u16; 65536] = build_utf8_pair_to_encoded_map();
+
+/// Number of complete groups processed at once by the dispatched kernels.
+pub(crate) const BLOCK_GROUPS: usize = 64;
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+const fn build_encoded_pair_to_utf8_map() -> [[u8; 2]; 3600] {
+    let mut map = [[0; 2]; 3600];
+    let mut index = 0;
+
+    while index < map.len() {
+        map[index] = [
+            ENCODED_TO_UTF8_MAP[index / 60],
+            ENCODED_TO_UTF8_MAP[index % 60],
+        ];
+        index += 1;
+    }
+
+    map
+}
+
+#[cfg(feature = "decoding-pair-table")]
+const fn build_utf8_pair_to_encoded_map() -> [u16; 65536] {
+    let mut map = [u16::MAX; 65536];
+    let mut index = 0;
+
+    while index < map.len() {
+        let b0 = index >> 8;
+        let b1 = index & 0xFF;
+
+        if b0 < UTF8_TO_ENCODED_MAP.len() && b1 < UTF8_TO_ENCODED_MAP.len() {
+            let c0 = UTF8_TO_ENCODED_MAP[b0];
+            let c1 = UTF8_TO_ENCODED_MAP[b1];
+
+            if c0 != 255 && c1 != 255 {
+                map[index] = 60 * c0 as u16 + c1 as u16;
+            }
+        }
+
+        index += 1;
+    }
+
+    map
+}
+
 // ----------------------------------------------------------------------------
 // TESTS ----------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -25,4 +79,43 @@ mod tests {
             assert_eq!(index, utf8 as usize, "Incorrect for {}", c)
         }
     }
+
+    #[test]
+    fn test_encoded_pair_mapping() {
+        for (index, pair) in ENCODED_PAIR_TO_UTF8_MAP.iter().enumerate() {
+            assert_eq!(
+                *pair,
+                [
+                    ENCODED_TO_UTF8_MAP[index / 60],
+                    ENCODED_TO_UTF8_MAP[index % 60]
+                ],
+                "Incorrect for {}",
+                index
+            );
+        }
+    }
+
+    #[cfg(feature = "decoding-pair-table")]
+    #[test]
+    fn test_pair_mapping() {
+        for b0 in 0..=255u8 {
+            for b1 in 0..=255u8 {
+                let pair = UTF8_PAIR_TO_ENCODED_MAP[((b0 as usize) << 8) | b1 as usize];
+                let c0 = *UTF8_TO_ENCODED_MAP.get(b0 as usize).unwrap_or(&255);
+                let c1 = *UTF8_TO_ENCODED_MAP.get(b1 as usize).unwrap_or(&255);
+
+                if c0 == 255 || c1 == 255 {
+                    assert_eq!(pair, u16::MAX, "Incorrect for {} {}", b0, b1);
+                } else {
+                    assert_eq!(
+                        pair,
+                        60 * c0 as u16 + c1 as u16,
+                        "Incorrect for {} {}",
+                        b0,
+                        b1
+                    );
+                }
+            }
+        }
+    }
 }
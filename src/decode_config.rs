@@ -0,0 +1,713 @@
+use std::borrow::Cow;
+
+use crate::decoding::{compute_chunk_fields, compute_decoded_size};
+use crate::dispatch;
+use crate::errors::{DecodingError, VerificationError};
+
+/// How the decoding handles encoded strings that are not canonical.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum CanonicalityPolicy {
+    /// Fails with [NotCanonical](VerificationError::NotCanonical).
+    #[default]
+    Strict,
+    /// Ignores the unused bits of the last incomplete group, so the result is the same as
+    /// decoding its canonical form.
+    Accept,
+}
+
+/// How the decoding handles encoded strings whose last group has an invalid length.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum LengthPolicy {
+    /// Fails with [InvalidLength](VerificationError::InvalidLength).
+    #[default]
+    Strict,
+    /// Accepts a last group of 1, 4 or 8 chars. Its last char does not encode any complete
+    /// byte, so it is treated as unused bits.
+    AllowDangling,
+    /// Drops the last char of a last group of 1, 4 or 8 chars without reading it, as if
+    /// the string had been truncated in transit.
+    Truncate,
+}
+
+/// The ASCII whitespace chars.
+const WHITESPACE: [u8; 5] = [b' ', b'\t', b'\n', b'\x0C', b'\r'];
+
+/// The line ending chars.
+const LINE_ENDINGS: [u8; 2] = [b'\n', b'\r'];
+
+/// The usual separators of formatted codes.
+const SEPARATORS: [u8; 3] = [b'-', b'_', b' '];
+
+/// A configurable G60 decoder.
+///
+/// ```rust
+/// # use g60::DecodeConfig;
+/// let decoded = DecodeConfig::new()
+///     .ignore_whitespace(true)
+///     .decode("Gt4CGFiHeh zRzjCF16\n")
+///     .unwrap();
+///
+/// assert_eq!(decoded, b"Hello, world!");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DecodeConfig {
+    /// The chars skipped while decoding, computed from the ignoring settings.
+    ignored: [bool; 256],
+    ignored_chars: [bool; 256],
+    ignore_whitespace: bool,
+    ignore_line_endings: bool,
+    ignore_separators: bool,
+    correct_confusables: bool,
+    canonicality: CanonicalityPolicy,
+    length: LengthPolicy,
+    output_limit: Option<usize>,
+    prefix: String,
+    suffix: String,
+}
+
+impl DecodeConfig {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds a config that behaves like [decode](crate::decode).
+    pub fn new() -> DecodeConfig {
+        DecodeConfig {
+            ignored: [false; 256],
+            ignored_chars: [false; 256],
+            ignore_whitespace: false,
+            ignore_line_endings: false,
+            ignore_separators: false,
+            correct_confusables: false,
+            canonicality: CanonicalityPolicy::Strict,
+            length: LengthPolicy::Strict,
+            output_limit: None,
+            prefix: String::new(),
+            suffix: String::new(),
+        }
+    }
+
+    // SETTERS ----------------------------------------------------------------
+
+    /// Sets whether ASCII whitespace is skipped while decoding.
+    ///
+    /// The chars shared with other settings, like `\n` with
+    /// [ignore_line_endings](DecodeConfig::ignore_line_endings), are skipped while any of
+    /// them is enabled.
+    pub fn ignore_whitespace(mut self, ignore: bool) -> Self {
+        self.ignore_whitespace = ignore;
+        self.update_ignored();
+        self
+    }
+
+    /// Sets whether line endings, i.e. `\n` and `\r`, are skipped while decoding, so the
+    /// strings wrapped by [EncodeConfig::wrap](crate::EncodeConfig::wrap) can be decoded.
+    pub fn ignore_line_endings(mut self, ignore: bool) -> Self {
+        self.ignore_line_endings = ignore;
+        self.update_ignored();
+        self
+    }
+
+    /// Sets whether the usual separators of formatted codes, i.e. `-`, `_` and spaces, are
+    /// skipped while decoding.
+    pub fn ignore_separators(mut self, ignore: bool) -> Self {
+        self.ignore_separators = ignore;
+        self.update_ignored();
+        self
+    }
+
+    /// Skips every char of `chars` while decoding.
+    ///
+    /// # Panics
+    /// Panics if any char of `chars` is not ASCII.
+    pub fn ignore_chars(mut self, chars: &str) -> Self {
+        assert!(chars.is_ascii(), "Only ASCII chars can be ignored");
+
+        for byte in chars.bytes() {
+            self.ignored_chars[byte as usize] = true;
+        }
+
+        self.update_ignored();
+        self
+    }
+
+    /// Sets whether the chars excluded from the G60 alphabet are read as the digits they
+    /// are usually mistaken for, i.e. `O` as `0` and `I` as `1`. Useful for codes typed
+    /// by humans.
+    pub fn correct_confusables(mut self, correct: bool) -> Self {
+        self.correct_confusables = correct;
+        self
+    }
+
+    /// Sets how non-canonical encoded strings are handled.
+    pub fn canonicality(mut self, policy: CanonicalityPolicy) -> Self {
+        self.canonicality = policy;
+        self
+    }
+
+    /// Sets how encoded strings with an invalid length are handled.
+    pub fn length(mut self, policy: LengthPolicy) -> Self {
+        self.length = policy;
+        self
+    }
+
+    /// Sets the maximum number of bytes the decoding can produce.
+    pub fn output_limit(mut self, limit: usize) -> Self {
+        self.output_limit = Some(limit);
+        self
+    }
+
+    /// Requires the encoded string to start with `prefix`, which is removed before decoding.
+    pub fn require_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Requires the encoded string to end with `suffix`, which is removed before decoding.
+    pub fn require_suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Decodes a G60 encoded string following this config.
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid G60 encoded string under this
+    /// config or its decoded content exceeds the output limit. The indexes of the errors
+    /// are relative to `encoded`, including the ignored chars.
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>, DecodingError> {
+        self.decode_with_normalization(encoded)
+            .map(|(decoded, _)| decoded)
+    }
+
+    /// Decodes a G60 encoded string following this config, also returning whether it was
+    /// not canonical and had to be normalized. That can only happen with
+    /// [CanonicalityPolicy::Accept].
+    ///
+    /// # Errors
+    /// The same as [decode](DecodeConfig::decode).
+    pub fn decode_with_normalization(
+        &self,
+        encoded: &str,
+    ) -> Result<(Vec<u8>, bool), DecodingError> {
+        let encoded = encoded
+            .strip_prefix(self.prefix.as_str())
+            .ok_or(DecodingError::MissingPrefix)?;
+        let encoded = encoded
+            .strip_suffix(self.suffix.as_str())
+            .ok_or(DecodingError::MissingSuffix)?;
+
+        let bytes = encoded.as_bytes();
+        let filtered = if bytes.iter().any(|v| self.must_rewrite(*v)) {
+            Cow::Owned(
+                bytes
+                    .iter()
+                    .filter(|v| !self.ignored[**v as usize])
+                    .map(|v| self.correct(*v))
+                    .collect(),
+            )
+        } else {
+            Cow::Borrowed(bytes)
+        };
+
+        self.decode_filtered(&filtered)
+            .map_err(|error| match error {
+                DecodingError::Verification(VerificationError::InvalidByte { index, byte }) => {
+                    DecodingError::Verification(VerificationError::InvalidByte {
+                        index: self.prefix.len() + self.original_index(bytes, index),
+                        byte,
+                    })
+                }
+                error => error,
+            })
+    }
+
+    // AUX METHODS ------------------------------------------------------------
+
+    /// Computes the skipped chars from all the ignoring settings, so disabling one does
+    /// not stop skipping the chars that another one still requires.
+    fn update_ignored(&mut self) {
+        self.ignored = self.ignored_chars;
+
+        for (enabled, bytes) in [
+            (self.ignore_whitespace, &WHITESPACE[..]),
+            (self.ignore_line_endings, &LINE_ENDINGS[..]),
+            (self.ignore_separators, &SEPARATORS[..]),
+        ] {
+            if enabled {
+                for byte in bytes {
+                    self.ignored[*byte as usize] = true;
+                }
+            }
+        }
+    }
+
+    fn decode_filtered(&self, bytes: &[u8]) -> Result<(Vec<u8>, bool), DecodingError> {
+        // Check length.
+        let mut bytes = bytes;
+        let mut last_group_length = bytes.len() - bytes.len() / 11 * 11;
+        match (last_group_length, self.length) {
+            (1 | 4 | 8, LengthPolicy::Strict) => {
+                return Err(DecodingError::Verification(
+                    VerificationError::InvalidLength,
+                ));
+            }
+            (1 | 4 | 8, LengthPolicy::Truncate) => {
+                bytes = &bytes[..bytes.len() - 1];
+                last_group_length -= 1;
+            }
+            _ => {}
+        }
+
+        let required_size = compute_decoded_size(bytes.len());
+        if let Some(limit) = self.output_limit {
+            if required_size > limit {
+                return Err(DecodingError::OutputLimitExceeded {
+                    limit,
+                    required: required_size,
+                });
+            }
+        }
+
+        let mut result = vec![0; required_size];
+        let mut normalized = false;
+
+        // Complete groups.
+        let complete_groups_length = bytes.len() - last_group_length;
+        let (complete_slice, last_slice) = result.split_at_mut(complete_groups_length / 11 * 8);
+        dispatch::decode_groups(0, &bytes[..complete_groups_length], complete_slice)?;
+
+        // Last incomplete group.
+        if last_group_length != 0 {
+            let chunk = &bytes[complete_groups_length..];
+            let fields = compute_chunk_fields(complete_groups_length, chunk)?;
+
+            for (byte, field) in last_slice.iter_mut().zip(fields) {
+                *byte = u8::try_from(field).map_err(|_| VerificationError::NotCanonical)?;
+            }
+
+            if fields[last_slice.len()..].iter().any(|v| *v != 0) {
+                if self.canonicality == CanonicalityPolicy::Strict {
+                    return Err(DecodingError::Verification(VerificationError::NotCanonical));
+                }
+
+                normalized = true;
+            }
+        }
+
+        Ok((result, normalized))
+    }
+
+    #[inline(always)]
+    fn must_rewrite(&self, byte: u8) -> bool {
+        self.ignored[byte as usize] || (self.correct_confusables && matches!(byte, b'O' | b'I'))
+    }
+
+    #[inline(always)]
+    fn correct(&self, byte: u8) -> u8 {
+        match byte {
+            b'O' if self.correct_confusables => b'0',
+            b'I' if self.correct_confusables => b'1',
+            _ => byte,
+        }
+    }
+
+    /// Maps an index of the filtered string to its position in `bytes`.
+    fn original_index(&self, bytes: &[u8], filtered_index: usize) -> usize {
+        bytes
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !self.ignored[**v as usize])
+            .nth(filtered_index)
+            .map_or(bytes.len(), |(index, _)| index)
+    }
+}
+
+impl Default for DecodeConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode, EncodeConfig, LineEnding};
+
+    #[test]
+    fn test_default_config_matches_decode() {
+        for test in [
+            "",
+            "Gt4CGFiHehzRzjCF16",
+            "Gt4CGFiHehzRzjCF17",
+            "Gt4CGFiHehzRzjCF1",
+            "Gt4CGFiHehzRzjCF1O",
+            "0000Kz00000",
+        ] {
+            assert_eq!(
+                DecodeConfig::new().decode(test),
+                decode(test),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+
+    #[test]
+    fn test_ignored_chars() {
+        let config = DecodeConfig::new()
+            .ignore_whitespace(true)
+            .ignore_chars("-");
+
+        let test = "Gt4C-GFiH-ehzR\r\n zjCF-16 ";
+        let decoded = config.decode(test).expect("The decoding must succeed");
+        assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+
+        let test = "Gt4C-GFiH-ehzR\r\n zOCF-16 ";
+        let error = config
+            .decode(test)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: 18,
+                byte: b'O',
+            }),
+            "Incorrect for '{}'",
+            test
+        );
+
+        let test = "Gt4C GFiH";
+        let error = DecodeConfig::new()
+            .decode(test)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: 4,
+                byte: b' ',
+            }),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_ignored_line_endings() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let config = DecodeConfig::new().ignore_line_endings(true);
+
+        for line_ending in [LineEnding::Lf, LineEnding::CrLf] {
+            let test = EncodeConfig::new()
+                .wrap(76)
+                .line_ending(line_ending)
+                .encode(&bytes);
+            let decoded = config.decode(&test).expect("The decoding must succeed");
+
+            assert_eq!(decoded, bytes, "Incorrect for {:?}", line_ending);
+        }
+
+        let test = "Gt4CGFiH ehzRzjCF1";
+        let error = config
+            .decode(test)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: 8,
+                byte: b' ',
+            }),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_ignored_line_endings_combinations() {
+        let test = "Gt4CGFiH\r\nehzRzjCF16";
+
+        for whitespace in [false, true] {
+            for line_endings in [false, true] {
+                let configs = [
+                    DecodeConfig::new()
+                        .ignore_whitespace(whitespace)
+                        .ignore_line_endings(line_endings),
+                    DecodeConfig::new()
+                        .ignore_line_endings(line_endings)
+                        .ignore_whitespace(whitespace),
+                ];
+
+                for config in configs {
+                    assert_eq!(
+                        config.decode(test).is_ok(),
+                        whitespace || line_endings,
+                        "Incorrect for whitespace {} and line endings {}",
+                        whitespace,
+                        line_endings
+                    );
+                    assert_eq!(
+                        config.decode("Gt4CGFiH\tehzRzjCF16").is_ok(),
+                        whitespace,
+                        "Incorrect for whitespace {} and line endings {}",
+                        whitespace,
+                        line_endings
+                    );
+                }
+            }
+        }
+
+        // Disabling a setting keeps the chars ignored by other ones.
+        let config = DecodeConfig::new()
+            .ignore_chars("\n")
+            .ignore_line_endings(true)
+            .ignore_line_endings(false);
+        assert_eq!(
+            config.decode("Gt4CGFiH\nehzRzjCF16"),
+            Ok(b"Hello, world!".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_ignored_separators() {
+        let config = DecodeConfig::new().ignore_separators(true);
+
+        for test in [
+            "Gt4C-GFiH-ehzR-zjCF-16",
+            "Gt4C_GFiH_ehzR_zjCF_16",
+            "Gt4C GFiH ehzR zjCF 16",
+        ] {
+            let decoded = config.decode(test).expect("The decoding must succeed");
+            assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+        }
+
+        let test = "Gt4C.GFiH";
+        let error = config
+            .decode(test)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: 4,
+                byte: b'.',
+            }),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_ignored_separators_combinations() {
+        for whitespace in [false, true] {
+            for separators in [false, true] {
+                let configs = [
+                    DecodeConfig::new()
+                        .ignore_whitespace(whitespace)
+                        .ignore_separators(separators),
+                    DecodeConfig::new()
+                        .ignore_separators(separators)
+                        .ignore_whitespace(whitespace),
+                ];
+
+                for config in configs {
+                    for (test, expected) in [
+                        ("Gt4CGFiH ehzRzjCF16", whitespace || separators),
+                        ("Gt4CGFiH-ehzRzjCF16", separators),
+                        ("Gt4CGFiH\tehzRzjCF16", whitespace),
+                    ] {
+                        assert_eq!(
+                            config.decode(test).is_ok(),
+                            expected,
+                            "Incorrect for '{}' with whitespace {} and separators {}",
+                            test,
+                            whitespace,
+                            separators
+                        );
+                    }
+                }
+            }
+        }
+
+        let config = DecodeConfig::new()
+            .ignore_chars("-")
+            .ignore_separators(true)
+            .ignore_separators(false);
+        assert_eq!(
+            config.decode("Gt4CGFiH-ehzRzjCF16"),
+            Ok(b"Hello, world!".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_tags() {
+        let config = DecodeConfig::new()
+            .require_prefix("g60:")
+            .require_suffix(";");
+
+        let test = "g60:Gt4CGFiHehzRzjCF16;";
+        let decoded = config.decode(test).expect("The decoding must succeed");
+        assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+
+        for (test, expected) in [
+            ("Gt4CGFiHehzRzjCF16;", DecodingError::MissingPrefix),
+            ("g60:Gt4CGFiHehzRzjCF16", DecodingError::MissingSuffix),
+            (
+                "g60:Gt4CGFiHehzRzjCO16;",
+                DecodingError::Verification(VerificationError::InvalidByte {
+                    index: 19,
+                    byte: b'O',
+                }),
+            ),
+        ] {
+            let error = config
+                .decode(test)
+                .expect_err("The decoding cannot succeed");
+            assert_eq!(error, expected, "Incorrect for '{}'", test);
+        }
+    }
+
+    #[test]
+    fn test_correct_confusables() {
+        let config = DecodeConfig::new().correct_confusables(true);
+
+        let test = "OOOIOOOOOOO";
+        let decoded = config.decode(test).expect("The decoding must succeed");
+        assert_eq!(
+            decoded,
+            decode("00010000000").unwrap(),
+            "Incorrect for '{}'",
+            test
+        );
+
+        let test = "Gt4CGFiHehzRzjCF16";
+        let decoded = config.decode(test).expect("The decoding must succeed");
+        assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+
+        let test = "OOOIOOOOOOO";
+        let error = DecodeConfig::new()
+            .decode(test)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: 0,
+                byte: b'O',
+            }),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_canonicality_policy() {
+        let config = DecodeConfig::new().canonicality(CanonicalityPolicy::Accept);
+
+        for (non_canonical, canonical) in
+            [("0f", "0U"), ("Gt4CGFiHehzRzjCF17", "Gt4CGFiHehzRzjCF16")]
+        {
+            assert_eq!(
+                config.decode(non_canonical),
+                decode(canonical),
+                "Incorrect for '{}'",
+                non_canonical
+            );
+        }
+
+        for test in ["0U", "Gt4CGFiHehzRzjCF16"] {
+            let (_, normalized) = config
+                .decode_with_normalization(test)
+                .expect("The decoding must succeed");
+            assert!(!normalized, "Incorrect for '{}'", test);
+        }
+
+        let test = "Gt4CGFiHehzRzjCF17";
+        let (decoded, normalized) = config
+            .decode_with_normalization(test)
+            .expect("The decoding must succeed");
+        assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+        assert!(normalized, "Incorrect for '{}'", test);
+
+        // Fields that do not fit in a byte cannot be accepted.
+        let test = "0000Kz00000";
+        let error = config
+            .decode(test)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::NotCanonical),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_length_policy() {
+        let config = DecodeConfig::new().length(LengthPolicy::AllowDangling);
+
+        for length in 0..40 {
+            let bytes: Vec<u8> = (0..length as u8).collect();
+            let encoded = encode(&bytes) + "0";
+
+            if let 1 | 4 | 8 = encoded.len() % 11 {
+                assert_eq!(
+                    config.decode(&encoded),
+                    Ok(bytes),
+                    "Incorrect for '{}'",
+                    encoded
+                );
+            }
+        }
+
+        let test = "Gt4CGFiHehzRzjCF16z";
+        let error = config
+            .decode(test)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::NotCanonical),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_truncate_length_policy() {
+        let config = DecodeConfig::new().length(LengthPolicy::Truncate);
+
+        for test in ["Gt4CGFiHehzRzjCF16z", "Gt4CGFiHehzRzjCF16O"] {
+            let decoded = config.decode(test).expect("The decoding must succeed");
+            assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+        }
+
+        let test = "Gt4CGFiHehzRzjCF16";
+        let decoded = config.decode(test).expect("The decoding must succeed");
+        assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+    }
+
+    #[test]
+    fn test_output_limit() {
+        let test = "Gt4CGFiHehzRzjCF16";
+        let decoded = DecodeConfig::new()
+            .output_limit(13)
+            .decode(test)
+            .expect("The decoding must succeed");
+        assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+
+        let error = DecodeConfig::new()
+            .output_limit(12)
+            .decode(test)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::OutputLimitExceeded {
+                limit: 12,
+                required: 13,
+            },
+            "Incorrect for '{}'",
+            test
+        );
+    }
+}
@@ -1,18 +1,111 @@
-use std::io::Write;
+use std::io::{ErrorKind, Write};
+use std::ops::Range;
 
-use crate::constants::UTF8_TO_ENCODED_MAP;
+use crate::constants::{BLOCK_GROUPS, UTF8_TO_ENCODED_MAP};
+use crate::decode_config::{CanonicalityPolicy, DecodeConfig};
+use crate::dispatch;
 use crate::errors::{DecodingError, VerificationError};
-use crate::utils::div_rem;
+use crate::utils::{div_rem, scratch_buffer};
 
 /// Decodes a G60 encoded string.
+///
+/// The string is verified in the same pass, so there is no need to call
+/// [verify](crate::verify) before decoding.
+///
+/// # Errors
+/// An error will be thrown if `encoded` is not a valid canonical G60 encoded string.
 pub fn decode(encoded: &str) -> Result<Vec<u8>, DecodingError> {
-    let mut slice = vec![0; compute_decoded_size(encoded.len())];
+    // Fast path for inputs shorter than a group.
+    if encoded.len() < 11 {
+        return decode_last_group(0, encoded.as_bytes())
+            .map(|(decoded, length)| decoded[..length].to_vec())
+            .map_err(DecodingError::Verification);
+    }
+
+    // Written through the writer so the output is not zero-initialized before being overwritten.
+    let mut slice = Vec::with_capacity(compute_decoded_size(encoded.len()));
 
-    decode_in_slice(encoded, &mut slice)?;
+    decode_in_writer(encoded, &mut slice)?;
 
     Ok(slice)
 }
 
+/// Decodes a G60 encoded string that may not be canonical, returning the decoded content
+/// and whether `encoded` was canonical, so there is no need to verify it in a second pass.
+///
+/// A non-canonical string decodes to the same bytes as its canonical form.
+///
+/// # Errors
+/// An error will be thrown if `encoded` is not a valid G60 encoded string.
+pub fn decode_with_canonicality(encoded: &str) -> Result<(Vec<u8>, bool), DecodingError> {
+    DecodeConfig::new()
+        .canonicality(CanonicalityPolicy::Accept)
+        .decode_with_normalization(encoded)
+        .map(|(decoded, normalized)| (decoded, !normalized))
+}
+
+/// Decodes a G60 encoded string whose decoded content cannot exceed `max_bytes`.
+///
+/// The limit is checked before decoding or allocating anything, so it is safe to use
+/// with untrusted input.
+///
+/// # Errors
+/// An error will be thrown if the decoded content would exceed `max_bytes` or `encoded`
+/// is not a valid canonical G60 encoded string.
+pub fn decode_with_limit(encoded: &str, max_bytes: usize) -> Result<Vec<u8>, DecodingError> {
+    let required_size = compute_decoded_size(encoded.len());
+
+    if required_size > max_bytes {
+        return Err(DecodingError::OutputLimitExceeded {
+            limit: max_bytes,
+            required: required_size,
+        });
+    }
+
+    decode(encoded)
+}
+
+/// Decodes a G60 encoded string whose decoded content must have exactly `N` bytes, e.g.
+/// a key or a hash, into an array.
+///
+/// # Errors
+/// An error will be thrown if the decoded content does not have `N` bytes or `encoded` is
+/// not a valid canonical G60 encoded string.
+pub fn decode_exact<const N: usize>(encoded: &str) -> Result<[u8; N], DecodingError> {
+    let actual_size = compute_decoded_size(encoded.len());
+
+    if actual_size != N {
+        return Err(DecodingError::UnexpectedDecodedLength {
+            expected: N,
+            actual: actual_size,
+        });
+    }
+
+    let mut result = [0; N];
+    decode_in_slice(encoded, &mut result)?;
+
+    Ok(result)
+}
+
+/// Decodes a G60 encoded string.
+/// The result is appended to `vector` and returns the number of elements appended.
+///
+/// # Errors
+/// An error will be thrown if `encoded` is not a valid canonical G60 encoded string.
+/// In that case `vector` is left as it was.
+pub fn decode_into(encoded: &str, vector: &mut Vec<u8>) -> Result<usize, DecodingError> {
+    let initial_length = vector.len();
+    vector.reserve_exact(compute_decoded_size(encoded.len()));
+
+    let result = decode_in_writer(encoded, vector);
+
+    if result.is_err() {
+        vector.truncate(initial_length);
+    }
+
+    result
+}
+
 /// Decodes a G60 encoded string.
 /// The result is placed into `slice` and returns the number of elements written.
 ///
@@ -29,7 +122,47 @@ pub fn decode_in_slice(encoded: &str, slice: &mut [u8]) -> Result<usize, Decodin
         });
     }
 
-    decode_in_writer(encoded, &mut std::io::Cursor::new(slice))
+    // Check length.
+    let last_group_length = bytes.len() - bytes.len() / 11 * 11;
+    if let 1 | 4 | 8 = last_group_length {
+        return Err(DecodingError::Verification(
+            VerificationError::InvalidLength,
+        ));
+    }
+
+    // Complete groups.
+    let complete_groups_length = bytes.len() - last_group_length;
+    let (complete_slice, last_slice) =
+        slice[..required_slice_size].split_at_mut(complete_groups_length / 11 * 8);
+    dispatch::decode_groups(0, &bytes[..complete_groups_length], complete_slice)?;
+
+    // Last incomplete group.
+    if !last_slice.is_empty() {
+        let chunk = &bytes[complete_groups_length..];
+        let decoded = compute_chunk(complete_groups_length, chunk)?;
+
+        if decoded[last_slice.len()..].iter().any(|v| *v != 0) {
+            return Err(DecodingError::Verification(VerificationError::NotCanonical));
+        }
+
+        last_slice.copy_from_slice(&decoded[..last_slice.len()]);
+    }
+
+    Ok(required_slice_size)
+}
+
+/// Decodes a G60 encoded string.
+/// The result is placed into `slice` and returns the written region.
+///
+/// # Errors
+/// An error will be thrown if `slice` does not have enough space to store the decoded string.
+pub fn decode_in_slice_ref<'a>(
+    encoded: &str,
+    slice: &'a mut [u8],
+) -> Result<&'a [u8], DecodingError> {
+    let written = decode_in_slice(encoded, slice)?;
+
+    Ok(&slice[..written])
 }
 
 /// Decodes a G60 encoded string.
@@ -38,53 +171,337 @@ pub fn decode_in_slice(encoded: &str, slice: &mut [u8]) -> Result<usize, Decodin
 /// # Errors
 /// An error will be thrown if the writing process fails.
 pub fn decode_in_writer<T: Write>(encoded: &str, writer: &mut T) -> Result<usize, DecodingError> {
+    decode_in_writer_with_progress(encoded, writer).map_err(|(_, error)| error)
+}
+
+/// Decodes a G60 encoded string.
+/// The result is written in `writer`.
+///
+/// # Errors
+/// An error will be thrown if the writing process fails or `encoded` is not a valid
+/// canonical G60 encoded string, along with the number of elements already written in
+/// `writer`, e.g. to truncate a file to its previous state.
+pub fn decode_in_writer_with_progress<T: Write>(
+    encoded: &str,
+    writer: &mut T,
+) -> Result<usize, (usize, DecodingError)> {
     let bytes = encoded.as_bytes();
     let required_slice_size = compute_decoded_size(bytes.len());
+    let mut written = 0;
 
     // Check length.
     let last_group_length = bytes.len() - bytes.len() / 11 * 11;
     if let 1 | 4 | 8 = last_group_length {
-        return Err(DecodingError::Verification(
-            VerificationError::InvalidLength,
+        return Err((
+            written,
+            DecodingError::Verification(VerificationError::InvalidLength),
         ));
     }
 
     // Complete groups.
+    let mut buffer = scratch_buffer::<{ BLOCK_GROUPS * 8 }>();
     let mut chunk_index = 0;
-    for chunk in bytes.chunks_exact(11) {
-        let decoded = compute_chunk(chunk_index, chunk)?;
+    for block in bytes[..bytes.len() - last_group_length].chunks(BLOCK_GROUPS * 11) {
+        let decoded = &mut buffer[..block.len() / 11 * 8];
+        dispatch::decode_groups(chunk_index, block, decoded)
+            .map_err(|e| (written, DecodingError::Verification(e)))?;
 
-        writer.write_all(&decoded).unwrap();
-        chunk_index += 11;
+        write_all_counting(writer, decoded, &mut written)?;
+        chunk_index += block.len();
     }
 
     // Last incomplete group.
     if last_group_length != 0 {
         let chunk = &bytes[bytes.len() - last_group_length..];
-        let decoded = compute_chunk(chunk_index, chunk)?;
-        let elements_to_write = compute_decoded_size(last_group_length);
+        let (decoded, elements_to_write) = decode_last_group(chunk_index, chunk)
+            .map_err(|e| (written, DecodingError::Verification(e)))?;
 
-        if decoded[elements_to_write..].iter().any(|v| *v != 0) {
-            return Err(DecodingError::Verification(VerificationError::NotCanonical));
+        write_all_counting(writer, &decoded[..elements_to_write], &mut written)?;
+    }
+
+    Ok(required_slice_size)
+}
+
+/// Decodes as many groups as possible from the front of a G60 encoded string, appending
+/// them to `vector`.
+///
+/// Returns the number of elements appended, the number of chars consumed from `encoded`
+/// and the error that stopped the decoding, if any. The decoding stops at the beginning
+/// of the first invalid group, so the consumed chars are always a valid G60 encoded string.
+pub fn decode_partial(
+    encoded: &str,
+    vector: &mut Vec<u8>,
+) -> (usize, usize, Option<VerificationError>) {
+    let bytes = encoded.as_bytes();
+    let initial_length = vector.len();
+    vector.reserve(compute_decoded_size(bytes.len()));
+
+    // Complete groups.
+    let last_group_length = bytes.len() - bytes.len() / 11 * 11;
+    let mut chunk_index = 0;
+    for block in bytes[..bytes.len() - last_group_length].chunks(BLOCK_GROUPS * 11) {
+        let block_start = vector.len();
+        vector.resize(block_start + block.len() / 11 * 8, 0);
+
+        if dispatch::decode_groups(chunk_index, block, &mut vector[block_start..]).is_err() {
+            // Keep the valid groups before the error.
+            vector.truncate(block_start);
+
+            for chunk in block.chunks_exact(11) {
+                match compute_chunk(chunk_index, chunk) {
+                    Ok(decoded) => vector.extend_from_slice(&decoded),
+                    Err(e) => return (vector.len() - initial_length, chunk_index, Some(e)),
+                }
+
+                chunk_index += 11;
+            }
         }
 
-        writer.write_all(&decoded[..elements_to_write]).unwrap();
+        chunk_index += block.len();
     }
 
-    Ok(required_slice_size)
+    // Last incomplete group.
+    match decode_last_group(chunk_index, &bytes[chunk_index..]) {
+        Ok((decoded, length)) => {
+            vector.extend_from_slice(&decoded[..length]);
+
+            (vector.len() - initial_length, bytes.len(), None)
+        }
+        Err(e) => (vector.len() - initial_length, chunk_index, Some(e)),
+    }
+}
+
+/// Decodes every valid group of a G60 encoded string, skipping the invalid ones.
+///
+/// Returns the decoded content and the spans of `encoded` that were skipped, sorted and
+/// merged when contiguous. Useful to recover data from partially corrupted sources.
+pub fn decode_lossy(encoded: &str) -> (Vec<u8>, Vec<Range<usize>>) {
+    let bytes = encoded.as_bytes();
+    let mut result = Vec::with_capacity(compute_decoded_size(bytes.len()));
+    let mut skipped: Vec<Range<usize>> = Vec::new();
+    let mut skip = |span: Range<usize>| match skipped.last_mut() {
+        Some(last) if last.end == span.start => last.end = span.end,
+        _ => skipped.push(span),
+    };
+
+    // Complete groups.
+    let last_group_length = bytes.len() - bytes.len() / 11 * 11;
+    let mut chunk_index = 0;
+    for block in bytes[..bytes.len() - last_group_length].chunks(BLOCK_GROUPS * 11) {
+        let block_start = result.len();
+        result.resize(block_start + block.len() / 11 * 8, 0);
+
+        if dispatch::decode_groups(chunk_index, block, &mut result[block_start..]).is_err() {
+            result.truncate(block_start);
+
+            for (i, chunk) in block.chunks_exact(11).enumerate() {
+                let index = chunk_index + i * 11;
+
+                match compute_chunk(index, chunk) {
+                    Ok(decoded) => result.extend_from_slice(&decoded),
+                    Err(_) => skip(index..index + 11),
+                }
+            }
+        }
+
+        chunk_index += block.len();
+    }
+
+    // Last incomplete group.
+    match decode_last_group(chunk_index, &bytes[chunk_index..]) {
+        Ok((decoded, length)) => result.extend_from_slice(&decoded[..length]),
+        Err(_) => skip(chunk_index..bytes.len()),
+    }
+
+    (result, skipped)
+}
+
+/// Decodes a G60 encoded string lazily, one group at a time, so the consumer can stop
+/// early or stream the bytes into any sink without allocating the whole result.
+///
+/// An invalid length is reported as the first item. Any other error is reported when its
+/// group is reached. The iterator ends after the first error.
+///
+/// ```rust
+/// let decoded: Result<Vec<u8>, _> = g60::decode_iter("Gt4CGFiHehzRzjCF16").collect();
+///
+/// assert_eq!(decoded.unwrap(), b"Hello, world!");
+/// ```
+pub fn decode_iter(encoded: &str) -> impl Iterator<Item = Result<u8, DecodingError>> + '_ {
+    DecodeIter::new(encoded.as_bytes())
+}
+
+/// The iterator behind [decode_iter].
+pub(crate) struct DecodeIter<'a> {
+    chunks: std::slice::Chunks<'a, u8>,
+    chunk_index: usize,
+    group: [u8; 8],
+    group_index: usize,
+    group_length: usize,
+    error: Option<DecodingError>,
+}
+
+impl<'a> DecodeIter<'a> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub(crate) fn new(bytes: &'a [u8]) -> DecodeIter<'a> {
+        let error = match bytes.len() % 11 {
+            1 | 4 | 8 => Some(DecodingError::Verification(
+                VerificationError::InvalidLength,
+            )),
+            _ => None,
+        };
+
+        DecodeIter {
+            chunks: bytes.chunks(11),
+            chunk_index: 0,
+            group: [0; 8],
+            group_index: 0,
+            group_length: 0,
+            error,
+        }
+    }
+}
+
+impl Iterator for DecodeIter<'_> {
+    type Item = Result<u8, DecodingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.group_index == self.group_length {
+            if let Some(error) = self.error.take() {
+                self.chunks = [].chunks(11);
+                return Some(Err(error));
+            }
+
+            let chunk = self.chunks.next()?;
+            let decoded = if chunk.len() == 11 {
+                compute_chunk(self.chunk_index, chunk).map(|v| (v, 8))
+            } else {
+                decode_last_group(self.chunk_index, chunk)
+            };
+
+            match decoded {
+                Ok((group, length)) => {
+                    self.group = group;
+                    self.group_index = 0;
+                    self.group_length = length;
+                    self.chunk_index += chunk.len();
+                }
+                Err(e) => {
+                    self.chunks = [].chunks(11);
+                    return Some(Err(DecodingError::Verification(e)));
+                }
+            }
+        }
+
+        let byte = self.group[self.group_index];
+        self.group_index += 1;
+
+        Some(Ok(byte))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.group_length - self.group_index;
+        let remaining = compute_decoded_size(self.chunks.len() * 11);
+
+        (0, Some(pending + remaining + 1))
+    }
 }
 
 // ----------------------------------------------------------------------------
 // AUX METHODS ----------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Decodes a G60 encoded string walking its groups like [verify](crate::verify), so the
+/// only possible errors are verification ones.
+pub(crate) fn decode_verified(encoded: &str) -> Result<Vec<u8>, VerificationError> {
+    let bytes = encoded.as_bytes();
+
+    // Check length.
+    let last_group_length = bytes.len() - bytes.len() / 11 * 11;
+    if let 1 | 4 | 8 = last_group_length {
+        return Err(VerificationError::InvalidLength);
+    }
+
+    // Complete groups.
+    let complete_groups_length = bytes.len() - last_group_length;
+    let mut result = Vec::with_capacity(compute_decoded_size(bytes.len()));
+    let mut buffer = scratch_buffer::<{ BLOCK_GROUPS * 8 }>();
+    let mut chunk_index = 0;
+    for block in bytes[..complete_groups_length].chunks(BLOCK_GROUPS * 11) {
+        let decoded = &mut buffer[..block.len() / 11 * 8];
+        dispatch::decode_groups(chunk_index, block, decoded)?;
+
+        result.extend_from_slice(decoded);
+        chunk_index += block.len();
+    }
+
+    // Last incomplete group.
+    let (decoded, length) =
+        decode_last_group(complete_groups_length, &bytes[complete_groups_length..])?;
+    result.extend_from_slice(&decoded[..length]);
+
+    Ok(result)
+}
+
+/// Decodes the last group of an encoded string, which may be empty or incomplete, returning
+/// the decoded bytes and how many of them belong to the result.
+///
+/// # Errors
+/// An error will be thrown if the group has an invalid length or it is not canonical.
+#[inline]
+pub(crate) fn decode_last_group(
+    chunk_index: usize,
+    chunk: &[u8],
+) -> Result<([u8; 8], usize), VerificationError> {
+    if chunk.is_empty() {
+        return Ok(([0; 8], 0));
+    }
+
+    if let 1 | 4 | 8 = chunk.len() {
+        return Err(VerificationError::InvalidLength);
+    }
+
+    let decoded = compute_chunk(chunk_index, chunk)?;
+    let length = compute_decoded_size(chunk.len());
+
+    if decoded[length..].iter().any(|v| *v != 0) {
+        return Err(VerificationError::NotCanonical);
+    }
+
+    Ok((decoded, length))
+}
+
+/// Like `write_all` but keeps `written` up to date even if it fails in the middle.
+fn write_all_counting<T: Write>(
+    writer: &mut T,
+    mut buf: &[u8],
+    written: &mut usize,
+) -> Result<(), (usize, DecodingError)> {
+    while !buf.is_empty() {
+        match writer.write(buf) {
+            Ok(0) => {
+                return Err((*written, DecodingError::WritingError(ErrorKind::WriteZero)));
+            }
+            Ok(v) => {
+                *written += v;
+                buf = &buf[v..];
+            }
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err((*written, DecodingError::WritingError(e.kind()))),
+        }
+    }
+
+    Ok(())
+}
+
 /// Computes `ceil(8 * encoded_length / 11)` faster using only integers.
 #[inline(always)]
 pub(crate) fn compute_decoded_size(encoded_length: usize) -> usize {
     (encoded_length << 3) / 11
 }
 
-#[inline]
+#[inline(always)]
 pub(crate) fn map_utf8_to_encoded(
     chunk_index: usize,
     index: usize,
@@ -106,45 +523,136 @@ pub(crate) fn map_utf8_to_encoded(
     }
 }
 
-#[inline]
+/// Maps the chars at `index` and `index + 1` to their combined value `60 * c0 + c1`.
+#[cfg(not(feature = "decoding-pair-table"))]
+#[inline(always)]
+pub(crate) fn map_utf8_pair_to_encoded(
+    chunk_index: usize,
+    index: usize,
+    chunk: &[u8],
+) -> Result<usize, VerificationError> {
+    let c0 = map_utf8_to_encoded(chunk_index, index, chunk)?;
+    let c1 = map_utf8_to_encoded(chunk_index, index + 1, chunk)?;
+
+    Ok(60 * c0 + c1)
+}
+
+/// Maps the chars at `index` and `index + 1` to their values `c0` and `c1`.
+#[cfg(not(feature = "decoding-pair-table"))]
+#[inline(always)]
+pub(crate) fn map_utf8_pair_to_encoded_split(
+    chunk_index: usize,
+    index: usize,
+    chunk: &[u8],
+) -> Result<(usize, usize), VerificationError> {
+    let c0 = map_utf8_to_encoded(chunk_index, index, chunk)?;
+    let c1 = map_utf8_to_encoded(chunk_index, index + 1, chunk)?;
+
+    Ok((c0, c1))
+}
+
+/// Maps the chars at `index` and `index + 1` to their values `c0` and `c1` with a single
+/// lookup.
+#[cfg(feature = "decoding-pair-table")]
+#[inline(always)]
+pub(crate) fn map_utf8_pair_to_encoded_split(
+    chunk_index: usize,
+    index: usize,
+    chunk: &[u8],
+) -> Result<(usize, usize), VerificationError> {
+    Ok(div_rem(
+        map_utf8_pair_to_encoded(chunk_index, index, chunk)?,
+        60,
+    ))
+}
+
+/// Maps the chars at `index` and `index + 1` to their combined value `60 * c0 + c1`
+/// with a single lookup.
+#[cfg(feature = "decoding-pair-table")]
+#[inline(always)]
+pub(crate) fn map_utf8_pair_to_encoded(
+    chunk_index: usize,
+    index: usize,
+    chunk: &[u8],
+) -> Result<usize, VerificationError> {
+    use crate::constants::UTF8_PAIR_TO_ENCODED_MAP;
+
+    if let Some(&[v0, v1]) = chunk.get(index..index + 2) {
+        let encoded = UTF8_PAIR_TO_ENCODED_MAP[((v0 as usize) << 8) | v1 as usize];
+        if encoded != u16::MAX {
+            return Ok(encoded as usize);
+        }
+    }
+
+    // Incomplete pair or invalid byte.
+    let c0 = map_utf8_to_encoded(chunk_index, index, chunk)?;
+    let c1 = map_utf8_to_encoded(chunk_index, index + 1, chunk)?;
+
+    Ok(60 * c0 + c1)
+}
+
+#[inline(always)]
 pub(crate) fn compute_chunk(
     chunk_index: usize,
     chunk: &[u8],
 ) -> Result<[u8; 8], VerificationError> {
-    let c0 = map_utf8_to_encoded(chunk_index, 0, chunk)?;
-    let c1 = map_utf8_to_encoded(chunk_index, 1, chunk)?;
-    let c2 = map_utf8_to_encoded(chunk_index, 2, chunk)?;
-    let c3 = map_utf8_to_encoded(chunk_index, 3, chunk)?;
-    let c4 = map_utf8_to_encoded(chunk_index, 4, chunk)?;
-    let c5 = map_utf8_to_encoded(chunk_index, 5, chunk)?;
+    let fields = compute_chunk_fields(chunk_index, chunk)?;
+
+    let mut result = [0; 8];
+    for (byte, field) in result.iter_mut().zip(fields) {
+        *byte = u8::try_from(field).map_err(|_| VerificationError::NotCanonical)?;
+    }
+
+    Ok(result)
+}
+
+/// Computes the 8 byte fields of a group without checking whether they fit in a byte.
+/// A field greater than `255` means the group is not canonical.
+#[inline(always)]
+pub(crate) fn compute_chunk_fields(
+    chunk_index: usize,
+    chunk: &[u8],
+) -> Result<[usize; 8], VerificationError> {
+    // The chars are read in pairs, except `c6`, which halves the lookups with the
+    // `decoding-pair-table` feature.
+    let c01 = map_utf8_pair_to_encoded(chunk_index, 0, chunk)?;
+    let (c2, c3) = map_utf8_pair_to_encoded_split(chunk_index, 2, chunk)?;
+    let c45 = map_utf8_pair_to_encoded(chunk_index, 4, chunk)?;
     let c6 = map_utf8_to_encoded(chunk_index, 6, chunk)?;
-    let c7 = map_utf8_to_encoded(chunk_index, 7, chunk)?;
-    let c8 = map_utf8_to_encoded(chunk_index, 8, chunk)?;
-    let c9 = map_utf8_to_encoded(chunk_index, 9, chunk)?;
-    let c10 = map_utf8_to_encoded(chunk_index, 10, chunk)?;
+    let c78 = map_utf8_pair_to_encoded(chunk_index, 7, chunk)?;
+    let c9_10 = map_utf8_pair_to_encoded(chunk_index, 9, chunk)?;
 
-    let (b1, r1) = div_rem(60 * c0 + c1, 14);
+    let (b1, r1) = div_rem(c01, 14);
     let (b2, r2) = div_rem(c2, 3);
-    let (b3, r3) = div_rem(c4, 20);
+    // `c4 / 20` and `60 * (c4 % 20) + c5`.
+    let (b3, r3_c5) = div_rem(c45, 1200);
     let aux = 3 * c3 + b3;
     let b3_bis = aux >> 1;
     let r3_bis = aux & 0x1;
-    let (b4, r4) = div_rem(60 * r3 + c5, 9);
+    let (b4, r4) = div_rem(r3_c5, 9);
     let b5 = c6 >> 1;
     let r5 = c6 & 0x1;
-    let (b6, r6) = div_rem(60 * c7 + c8, 24);
-    let (b7, r7) = div_rem(c9, 5);
-
-    let c_a = u8::try_from(b1).map_err(|_| VerificationError::NotCanonical)?;
-    let c_b = u8::try_from(r1 * 20 + b2).map_err(|_| VerificationError::NotCanonical)?;
-    let c_c = u8::try_from(r2 * 90 + b3_bis).map_err(|_| VerificationError::NotCanonical)?;
-    let c_d = u8::try_from(128 * r3_bis + b4).map_err(|_| VerificationError::NotCanonical)?;
-    let c_e = u8::try_from(r4 * 30 + b5).map_err(|_| VerificationError::NotCanonical)?;
-    let c_f = u8::try_from(r5 * 150 + b6).map_err(|_| VerificationError::NotCanonical)?;
-    let c_g = u8::try_from(r6 * 12 + b7).map_err(|_| VerificationError::NotCanonical)?;
-    let c_h = u8::try_from(60 * r7 + c10).map_err(|_| VerificationError::NotCanonical)?;
-
-    Ok([c_a, c_b, c_c, c_d, c_e, c_f, c_g, c_h])
+    let (b6, r6) = div_rem(c78, 24);
+    // `c9 / 5` and `60 * (c9 % 5) + c10`.
+    let (b7, r7_c10) = div_rem(c9_10, 300);
+
+    // Otherwise `b4` would overflow into the most significant bit of `c_d`.
+    let c_d = if b4 > 0x7F {
+        usize::MAX
+    } else {
+        128 * r3_bis + b4
+    };
+
+    Ok([
+        b1,
+        r1 * 20 + b2,
+        r2 * 90 + b3_bis,
+        c_d,
+        r4 * 30 + b5,
+        r5 * 150 + b6,
+        r6 * 12 + b7,
+        r7_c10,
+    ])
 }
 
 // ----------------------------------------------------------------------------
@@ -156,6 +664,8 @@ mod tests {
     use super::*;
     use crate::constants::ENCODED_TO_UTF8_MAP;
     use crate::encode;
+    use crate::encoding;
+    use rand::{Rng, SeedableRng};
     use std::collections::HashSet;
 
     #[test]
@@ -185,6 +695,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_decoded_correct_values_across_blocks() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(BLOCK_GROUPS * 8 * 3 + 5).collect();
+        let encoded = encode(&bytes);
+        let decoded = decode(&encoded).expect("The decoding must succeed");
+
+        assert_eq!(bytes, decoded, "Incorrect decoding across blocks");
+
+        let mut encoded = encoded.into_bytes();
+        encoded[BLOCK_GROUPS * 11 * 2 + 3] = b'I';
+        let encoded = String::from_utf8(encoded).unwrap();
+        let error = decode(&encoded).expect_err("The decoding cannot succeed");
+
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: BLOCK_GROUPS * 11 * 2 + 3,
+                byte: b'I',
+            }),
+            "Incorrect error across blocks"
+        );
+    }
+
+    /// This test checks that every group accepted by the decoding is the encoding of the
+    /// decoded bytes.
+    #[test]
+    fn test_decoded_groups_are_canonical() {
+        let mut random = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..100_000 {
+            let chunk: Vec<u8> = (0..11)
+                .map(|_| ENCODED_TO_UTF8_MAP[random.gen_range(0..60)])
+                .collect();
+
+            if let Ok(decoded) = compute_chunk(0, &chunk) {
+                assert_eq!(
+                    encoding::compute_chunk(&decoded).to_vec(),
+                    chunk,
+                    "Not canonical for {:?}",
+                    chunk
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_decode_in_writer() {
         let test = "Gt4CGFiHehzRzjCF16";
@@ -198,6 +753,271 @@ mod tests {
         assert_eq!(result_vector, result, "Incorrect slice result");
     }
 
+    #[test]
+    fn test_decode_exact() {
+        let test = "Gt4CGFiHehzRzjCF16";
+        let decoded = decode_exact::<13>(test).expect("The decoding must succeed");
+        assert_eq!(&decoded, b"Hello, world!", "Incorrect for '{}'", test);
+
+        for (error, expected) in [
+            (decode_exact::<12>(test).map(|_| ()), 12),
+            (decode_exact::<14>(test).map(|_| ()), 14),
+        ] {
+            assert_eq!(
+                error,
+                Err(DecodingError::UnexpectedDecodedLength {
+                    expected,
+                    actual: 13,
+                }),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+
+        let test = "Gt4CGFiHehzRzjCF17";
+        let error = decode_exact::<13>(test).expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::NotCanonical),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_decode_with_canonicality() {
+        for (test, expected_canonical) in
+            [("Gt4CGFiHehzRzjCF16", true), ("Gt4CGFiHehzRzjCF17", false)]
+        {
+            let (decoded, canonical) =
+                decode_with_canonicality(test).expect("The decoding must succeed");
+
+            assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+            assert_eq!(canonical, expected_canonical, "Incorrect for '{}'", test);
+        }
+
+        let test = "0000Kz00000";
+        let error = decode_with_canonicality(test).expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::NotCanonical),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_decode_with_limit() {
+        let test = "Gt4CGFiHehzRzjCF16";
+        let decoded = decode_with_limit(test, 13).expect("The decoding must succeed");
+        assert_eq!(decoded, b"Hello, world!", "Incorrect for '{}'", test);
+
+        let error = decode_with_limit(test, 12).expect_err("The decoding cannot succeed");
+        assert_eq!(
+            error,
+            DecodingError::OutputLimitExceeded {
+                limit: 12,
+                required: 13,
+            },
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_decode_into() {
+        let mut result = b"user:".to_vec();
+        let decoded_chars =
+            decode_into("Gt4CGFiHehzRzjCF16", &mut result).expect("The decoding must succeed");
+
+        assert_eq!(decoded_chars, 13, "Incorrect chars");
+        assert_eq!(result, b"user:Hello, world!", "Incorrect result");
+
+        // The previous content is kept on error.
+        let test = "Gt4CGFiHehzRzjCF16Gt4CGFiHehO";
+        let error = decode_into(test, &mut result).expect_err("The decoding cannot succeed");
+
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: 28,
+                byte: b'O',
+            }),
+            "Incorrect for '{}'",
+            test
+        );
+        assert_eq!(result, b"user:Hello, world!", "Incorrect result");
+    }
+
+    #[test]
+    fn test_decode_partial() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(8 * BLOCK_GROUPS * 2 + 13)
+            .collect();
+        let encoded = encode(&bytes);
+
+        let mut result = b"user:".to_vec();
+        let (written, consumed, error) = decode_partial(&encoded, &mut result);
+        assert_eq!(written, bytes.len(), "Incorrect written");
+        assert_eq!(consumed, encoded.len(), "Incorrect consumed");
+        assert_eq!(error, None, "Incorrect error");
+        assert_eq!(&result[..5], b"user:", "Incorrect prefix");
+        assert_eq!(&result[5..], bytes, "Incorrect result");
+
+        // Stops at the group of the error.
+        for (index, expected_consumed) in [
+            (0, 0),
+            (11 * 70 + 3, 11 * 70),
+            (encoded.len() - 1, 11 * 129),
+        ] {
+            let mut test = encoded.clone().into_bytes();
+            test[index] = b'O';
+            let test = String::from_utf8(test).unwrap();
+
+            let mut result = Vec::new();
+            let (written, consumed, error) = decode_partial(&test, &mut result);
+            assert_eq!(
+                consumed, expected_consumed,
+                "Incorrect consumed for {}",
+                index
+            );
+            assert_eq!(
+                written,
+                consumed / 11 * 8,
+                "Incorrect written for {}",
+                index
+            );
+            assert_eq!(result, bytes[..written], "Incorrect result for {}", index);
+            assert_eq!(
+                error,
+                Some(VerificationError::InvalidByte { index, byte: b'O' }),
+                "Incorrect error for {}",
+                index
+            );
+        }
+
+        let test = "Gt4CGFiHehzRzjCF16z";
+        let mut result = Vec::new();
+        let (written, consumed, error) = decode_partial(test, &mut result);
+        assert_eq!(written, 8, "Incorrect written for '{}'", test);
+        assert_eq!(consumed, 11, "Incorrect consumed for '{}'", test);
+        assert_eq!(
+            error,
+            Some(VerificationError::InvalidLength),
+            "Incorrect error for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_decode_iter() {
+        for length in [0, 1, 7, 8, 13, 100] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = encode(&bytes);
+            let decoded: Result<Vec<u8>, _> = decode_iter(&encoded).collect();
+
+            assert_eq!(decoded, Ok(bytes), "Incorrect for '{}'", encoded);
+        }
+
+        // Stops at the first error, after the bytes of the valid groups.
+        let test = "Gt4CGFiHehzRzjCF1O";
+        let decoded: Vec<_> = decode_iter(test).collect();
+        assert_eq!(decoded.len(), 9, "Incorrect for '{}'", test);
+        assert_eq!(
+            decoded[8],
+            Err(DecodingError::Verification(
+                VerificationError::InvalidByte {
+                    index: 17,
+                    byte: b'O',
+                }
+            )),
+            "Incorrect for '{}'",
+            test
+        );
+
+        let test = "Gt4CGFiHehzRzjCF16z";
+        let decoded: Vec<_> = decode_iter(test).collect();
+        assert_eq!(
+            decoded,
+            vec![Err(DecodingError::Verification(
+                VerificationError::InvalidLength
+            ))],
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_decode_lossy() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(8 * BLOCK_GROUPS * 2 + 13)
+            .collect();
+        let encoded = encode(&bytes);
+
+        let (result, skipped) = decode_lossy(&encoded);
+        assert_eq!(result, bytes, "Incorrect result");
+        assert!(skipped.is_empty(), "Incorrect skipped");
+
+        // Corrupt groups 0, 70, 71 and the last one.
+        let mut test = encoded.clone().into_bytes();
+        let last_index = test.len() - 1;
+        for index in [3, 11 * 70, 11 * 71 + 10, last_index] {
+            test[index] = b'O';
+        }
+        let test = String::from_utf8(test).unwrap();
+
+        let (result, skipped) = decode_lossy(&test);
+        let mut expected = bytes[8..8 * 70].to_vec();
+        expected.extend_from_slice(&bytes[8 * 72..8 * 129]);
+
+        assert_eq!(result, expected, "Incorrect result");
+        assert_eq!(
+            skipped,
+            vec![0..11, 11 * 70..11 * 72, 11 * 129..encoded.len()],
+            "Incorrect skipped"
+        );
+    }
+
+    #[test]
+    fn test_decode_in_writer_with_progress() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(8 * BLOCK_GROUPS * 2 + 13)
+            .collect();
+        let mut encoded = encode(&bytes).into_bytes();
+        encoded[11 * BLOCK_GROUPS + 3] = b'O';
+        let test = String::from_utf8(encoded).unwrap();
+
+        let mut result = Vec::new();
+        let (written, error) = decode_in_writer_with_progress(&test, &mut result)
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(written, 8 * BLOCK_GROUPS, "Incorrect written");
+        assert_eq!(result, bytes[..written], "Incorrect result");
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: 11 * BLOCK_GROUPS + 3,
+                byte: b'O',
+            }),
+            "Incorrect error"
+        );
+
+        // Writing errors.
+        let test = encode(&bytes);
+        let mut result = [0; 100];
+        let (written, error) = decode_in_writer_with_progress(&test, &mut result.as_mut_slice())
+            .expect_err("The decoding cannot succeed");
+        assert_eq!(written, 100, "Incorrect written");
+        assert_eq!(result, bytes[..100], "Incorrect result");
+        assert_eq!(
+            error,
+            DecodingError::WritingError(ErrorKind::WriteZero),
+            "Incorrect error"
+        );
+    }
+
     /// This will test also `decode_in_slice_unchecked` and `decode_in_writer_unchecked`.
     #[test]
     fn test_decode_in_slice_exact_slice() {
@@ -227,6 +1047,29 @@ mod tests {
         assert_eq!(result_slice, result, "Incorrect slice result");
     }
 
+    #[test]
+    fn test_decode_in_slice_ref() {
+        let test = "Gt4CGFiHehzRzjCF16";
+        let mut result_slice = vec![0; 15];
+        let result =
+            decode_in_slice_ref(test, &mut result_slice).expect("The decoding must succeed");
+
+        assert_eq!(result, b"Hello, world!", "Incorrect result");
+
+        let error = decode_in_slice_ref(test, &mut result_slice[..10])
+            .expect_err("The decoding cannot succeed");
+
+        assert_eq!(
+            error,
+            DecodingError::NotEnoughSpaceInSlice {
+                actual: 10,
+                required: 13,
+            },
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
     #[test]
     fn test_decode_in_slice_shorter_slice() {
         let test = "Gt4CGFiHehzRzjCF16";
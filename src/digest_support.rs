@@ -0,0 +1,110 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+
+use digest::generic_array::{ArrayLength, GenericArray};
+use digest::Digest;
+
+use crate::{encode, G60String};
+
+/// A hash output, or any other [GenericArray] of bytes, displayed as a G60 encoded string,
+/// like hex crates do with hexadecimal.
+///
+/// ```rust
+/// # use sha2::{Digest, Sha256};
+/// let digest = g60::G60Digest::from(Sha256::digest(b"Hello, world!"));
+///
+/// assert_eq!(digest.to_string().len(), 44);
+/// assert_eq!(digest.to_string(), g60::encode_digest::<Sha256>(b"Hello, world!"));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct G60Digest<N: ArrayLength<u8>>(pub GenericArray<u8, N>);
+
+impl<N: ArrayLength<u8>> G60Digest<N> {
+    // METHODS ----------------------------------------------------------------
+
+    /// Encodes the bytes as a [G60String].
+    pub fn to_g60_string(&self) -> G60String {
+        G60String::encode(&self.0)
+    }
+}
+
+impl<N: ArrayLength<u8>> Debug for G60Digest<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&encode(&self.0), f)
+    }
+}
+
+impl<N: ArrayLength<u8>> Display for G60Digest<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&encode(&self.0))
+    }
+}
+
+impl<N: ArrayLength<u8>> Deref for G60Digest<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<N: ArrayLength<u8>> From<GenericArray<u8, N>> for G60Digest<N> {
+    fn from(bytes: GenericArray<u8, N>) -> Self {
+        G60Digest(bytes)
+    }
+}
+
+impl<N: ArrayLength<u8>> From<G60Digest<N>> for G60String {
+    fn from(digest: G60Digest<N>) -> Self {
+        digest.to_g60_string()
+    }
+}
+
+/// Hashes `data` with `D` and encodes the hash output.
+pub fn encode_digest<D: Digest>(data: impl AsRef<[u8]>) -> G60String {
+    G60String::encode(&D::digest(data))
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Sha256, Sha512};
+
+    use super::*;
+
+    #[test]
+    fn test_encode_digest() {
+        for data in [&b""[..], b"Hello, world!"] {
+            let hash = Sha256::digest(data);
+            let encoded = encode_digest::<Sha256>(data);
+
+            assert_eq!(encoded.len(), 44, "Incorrect for {:?}", data);
+            assert_eq!(
+                encoded.decode(),
+                hash.as_slice(),
+                "Incorrect for {:?}",
+                data
+            );
+            assert_eq!(
+                G60Digest::from(hash).to_string(),
+                encoded,
+                "Incorrect for {:?}",
+                data
+            );
+        }
+
+        assert_eq!(encode_digest::<Sha512>(b"").len(), 88);
+    }
+
+    #[test]
+    fn test_g60_digest() {
+        let digest = G60Digest::from(Sha256::digest(b"Hello, world!"));
+
+        assert_eq!(digest.len(), 32);
+        assert_eq!(format!("{:?}", digest), format!("{:?}", digest.to_string()));
+        assert_eq!(G60String::from(digest.clone()), digest.to_g60_string());
+    }
+}
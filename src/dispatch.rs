@@ -0,0 +1,307 @@
+use std::sync::OnceLock;
+
+use crate::decoding;
+use crate::encoding;
+use crate::errors::VerificationError;
+
+/// The set of instructions selected at runtime to process complete groups.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Backend {
+    Scalar,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Sse41,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Avx2,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+type EncodeGroupsFn = fn(&[u8], &mut [u8]);
+type DecodeGroupsFn = fn(usize, &[u8], &mut [u8]) -> Result<(), VerificationError>;
+
+struct Kernels {
+    encode_groups: EncodeGroupsFn,
+    decode_groups: DecodeGroupsFn,
+}
+
+/// Encodes the complete 8-byte groups of `content` into `slice` using the best backend
+/// available in the current CPU.
+///
+/// `content.len()` must be a multiple of 8 and `slice` must have exactly 11 bytes per group.
+#[inline]
+pub(crate) fn encode_groups(content: &[u8], slice: &mut [u8]) {
+    (kernels().encode_groups)(content, slice)
+}
+
+/// Decodes the complete 11-char groups of `encoded` into `slice` using the best backend
+/// available in the current CPU. `chunk_index` is the position of `encoded` inside the
+/// whole encoded string, used to report errors.
+///
+/// `encoded.len()` must be a multiple of 11 and `slice` must have exactly 8 bytes per group.
+#[inline]
+pub(crate) fn decode_groups(
+    chunk_index: usize,
+    encoded: &[u8],
+    slice: &mut [u8],
+) -> Result<(), VerificationError> {
+    (kernels().decode_groups)(chunk_index, encoded, slice)
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn kernels() -> &'static Kernels {
+    static KERNELS: OnceLock<Kernels> = OnceLock::new();
+
+    KERNELS.get_or_init(|| kernels_for(detect_backend()))
+}
+
+fn detect_backend() -> Backend {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            return Backend::Avx2;
+        }
+
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            return Backend::Sse41;
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return Backend::Neon;
+        }
+    }
+
+    Backend::Scalar
+}
+
+fn kernels_for(backend: Backend) -> Kernels {
+    match backend {
+        Backend::Scalar => Kernels {
+            encode_groups: encode_groups_scalar,
+            decode_groups: decode_groups_scalar,
+        },
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Backend::Sse41 => Kernels {
+            encode_groups: x86::encode_groups_sse41,
+            decode_groups: x86::decode_groups_sse41,
+        },
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        Backend::Avx2 => Kernels {
+            encode_groups: x86::encode_groups_avx2,
+            decode_groups: x86::decode_groups_avx2,
+        },
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon => Kernels {
+            encode_groups: aarch64::encode_groups_neon,
+            decode_groups: aarch64::decode_groups_neon,
+        },
+    }
+}
+
+#[inline(always)]
+fn encode_groups_generic(content: &[u8], slice: &mut [u8]) {
+    for (chunk, result) in content.chunks_exact(8).zip(slice.chunks_exact_mut(11)) {
+        result.copy_from_slice(&encoding::compute_chunk(chunk));
+    }
+}
+
+#[inline(always)]
+fn decode_groups_generic(
+    mut chunk_index: usize,
+    encoded: &[u8],
+    slice: &mut [u8],
+) -> Result<(), VerificationError> {
+    for (chunk, result) in encoded.chunks_exact(11).zip(slice.chunks_exact_mut(8)) {
+        result.copy_from_slice(&decoding::compute_chunk(chunk_index, chunk)?);
+        chunk_index += 11;
+    }
+
+    Ok(())
+}
+
+fn encode_groups_scalar(content: &[u8], slice: &mut [u8]) {
+    encode_groups_generic(content, slice)
+}
+
+fn decode_groups_scalar(
+    chunk_index: usize,
+    encoded: &[u8],
+    slice: &mut [u8],
+) -> Result<(), VerificationError> {
+    decode_groups_generic(chunk_index, encoded, slice)
+}
+
+/// The kernels are the same code compiled with wider instruction sets enabled, which lets
+/// the compiler vectorize the group loops. They are only reachable through [kernels_for]
+/// after the features have been detected.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    use super::*;
+
+    pub(super) fn encode_groups_sse41(content: &[u8], slice: &mut [u8]) {
+        // SAFETY: only selected when `sse4.1` has been detected.
+        unsafe { encode_groups_sse41_impl(content, slice) }
+    }
+
+    pub(super) fn decode_groups_sse41(
+        chunk_index: usize,
+        encoded: &[u8],
+        slice: &mut [u8],
+    ) -> Result<(), VerificationError> {
+        // SAFETY: only selected when `sse4.1` has been detected.
+        unsafe { decode_groups_sse41_impl(chunk_index, encoded, slice) }
+    }
+
+    pub(super) fn encode_groups_avx2(content: &[u8], slice: &mut [u8]) {
+        // SAFETY: only selected when `avx2` has been detected.
+        unsafe { encode_groups_avx2_impl(content, slice) }
+    }
+
+    pub(super) fn decode_groups_avx2(
+        chunk_index: usize,
+        encoded: &[u8],
+        slice: &mut [u8],
+    ) -> Result<(), VerificationError> {
+        // SAFETY: only selected when `avx2` has been detected.
+        unsafe { decode_groups_avx2_impl(chunk_index, encoded, slice) }
+    }
+
+    #[target_feature(enable = "sse4.1")]
+    unsafe fn encode_groups_sse41_impl(content: &[u8], slice: &mut [u8]) {
+        encode_groups_generic(content, slice)
+    }
+
+    #[target_feature(enable = "sse4.1")]
+    unsafe fn decode_groups_sse41_impl(
+        chunk_index: usize,
+        encoded: &[u8],
+        slice: &mut [u8],
+    ) -> Result<(), VerificationError> {
+        decode_groups_generic(chunk_index, encoded, slice)
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn encode_groups_avx2_impl(content: &[u8], slice: &mut [u8]) {
+        encode_groups_generic(content, slice)
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn decode_groups_avx2_impl(
+        chunk_index: usize,
+        encoded: &[u8],
+        slice: &mut [u8],
+    ) -> Result<(), VerificationError> {
+        decode_groups_generic(chunk_index, encoded, slice)
+    }
+}
+
+/// See the `x86` module.
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use super::*;
+
+    pub(super) fn encode_groups_neon(content: &[u8], slice: &mut [u8]) {
+        // SAFETY: only selected when `neon` has been detected.
+        unsafe { encode_groups_neon_impl(content, slice) }
+    }
+
+    pub(super) fn decode_groups_neon(
+        chunk_index: usize,
+        encoded: &[u8],
+        slice: &mut [u8],
+    ) -> Result<(), VerificationError> {
+        // SAFETY: only selected when `neon` has been detected.
+        unsafe { decode_groups_neon_impl(chunk_index, encoded, slice) }
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn encode_groups_neon_impl(content: &[u8], slice: &mut [u8]) {
+        encode_groups_generic(content, slice)
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn decode_groups_neon_impl(
+        chunk_index: usize,
+        encoded: &[u8],
+        slice: &mut [u8],
+    ) -> Result<(), VerificationError> {
+        decode_groups_generic(chunk_index, encoded, slice)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn available_backends() -> Vec<Backend> {
+        let detected = detect_backend();
+        let mut backends = vec![Backend::Scalar];
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                backends.push(Backend::Sse41);
+            }
+
+            if std::arch::is_x86_feature_detected!("avx2") {
+                backends.push(Backend::Avx2);
+            }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                backends.push(Backend::Neon);
+            }
+        }
+
+        assert!(backends.contains(&detected), "Undetected {:?}", detected);
+        backends
+    }
+
+    #[test]
+    fn test_backends_match_scalar() {
+        let content: Vec<u8> = (0..=255u8).cycle().take(8 * 100).collect();
+
+        let mut expected_encoded = vec![0; 11 * 100];
+        encode_groups_scalar(&content, &mut expected_encoded);
+
+        for backend in available_backends() {
+            let kernels = kernels_for(backend);
+
+            let mut encoded = vec![0; 11 * 100];
+            (kernels.encode_groups)(&content, &mut encoded);
+            assert_eq!(
+                encoded, expected_encoded,
+                "Incorrect encoding for {:?}",
+                backend
+            );
+
+            let mut decoded = vec![0; 8 * 100];
+            (kernels.decode_groups)(0, &encoded, &mut decoded).expect("The decoding must succeed");
+            assert_eq!(decoded, content, "Incorrect decoding for {:?}", backend);
+
+            encoded[15] = b'O';
+            let error = (kernels.decode_groups)(22, &encoded, &mut decoded)
+                .expect_err("The decoding cannot succeed");
+            assert_eq!(
+                error,
+                VerificationError::InvalidByte {
+                    index: 37,
+                    byte: b'O',
+                },
+                "Incorrect error for {:?}",
+                backend
+            );
+        }
+    }
+}
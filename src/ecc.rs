@@ -0,0 +1,422 @@
+//! Reed–Solomon error correction over the symbols of a G60 encoded string, for strings
+//! that may be damaged when printed, scanned or typed.
+//!
+//! The chars of the encoded string are split in blocks and each block is followed by
+//! `parity` parity symbols computed over GF(61), the smallest field that fits the 60 values
+//! of a char. A parity symbol can take 61 values, so it is written with two chars.
+//! Each block can be repaired if at most `parity / 2` of its symbols are damaged.
+//!
+//! ```rust
+//! let protected = g60::ecc::encode(b"Hello, world!", 4);
+//! assert_eq!(protected.len(), 18 + 4 * 2);
+//!
+//! let mut damaged = protected.into_bytes();
+//! damaged[3] = b'x';
+//! damaged[10] = b'O';
+//! let damaged = String::from_utf8(damaged).unwrap();
+//!
+//! assert_eq!(
+//!     g60::ecc::decode(&damaged, 4).unwrap(),
+//!     (b"Hello, world!".to_vec(), 2)
+//! );
+//! ```
+
+use crate::constants::{ENCODED_TO_UTF8_MAP, UTF8_TO_ENCODED_MAP};
+use crate::errors::DecodingError;
+use crate::{decode as decode_g60, encode as encode_g60};
+
+/// Order of the field.
+const FIELD: u32 = 61;
+
+/// A generator of the multiplicative group of GF(61).
+const GENERATOR: u32 = 2;
+
+/// Maximum number of symbols of a block, data plus parity.
+const MAX_BLOCK_SYMBOLS: usize = FIELD as usize - 1;
+
+/// Encodes a list of bytes into a G60 encoding format protected by `parity` parity
+/// symbols per block.
+///
+/// # Panics
+/// Panics if `parity` is not between 1 and 59.
+pub fn encode(content: &[u8], parity: usize) -> String {
+    check_parity(parity);
+
+    let encoded = encode_g60(content);
+    let generator = generator_polynomial(parity);
+    let data_symbols = MAX_BLOCK_SYMBOLS - parity;
+    let blocks = encoded.len().div_ceil(data_symbols);
+    let mut result = Vec::with_capacity(encoded.len() + blocks * parity * 2);
+
+    for block in encoded.as_bytes().chunks(data_symbols) {
+        let symbols: Vec<u32> = block
+            .iter()
+            .map(|v| UTF8_TO_ENCODED_MAP[*v as usize] as u32)
+            .collect();
+
+        result.extend_from_slice(block);
+        result.extend(encode_parity_chars(&compute_parity(&symbols, &generator)));
+    }
+
+    // SAFETY: the encoded string contains only ASCII characters.
+    unsafe { String::from_utf8_unchecked(result) }
+}
+
+/// Decodes a G60 encoded string protected by [encode] with the same `parity`, repairing
+/// the damaged symbols. Returns the decoded content and the number of repaired symbols.
+///
+/// A block with more than `parity / 2` damaged symbols cannot be repaired and, in rare
+/// cases, it can be repaired into a wrong value.
+///
+/// # Errors
+/// An error will be thrown if any block cannot be repaired or the repaired string is not
+/// a valid canonical G60 encoded string.
+///
+/// # Panics
+/// Panics if `parity` is not between 1 and 59.
+pub fn decode(encoded: &str, parity: usize) -> Result<(Vec<u8>, usize), DecodingError> {
+    check_parity(parity);
+
+    let bytes = encoded.as_bytes();
+    let block_length = MAX_BLOCK_SYMBOLS - parity + parity * 2;
+    let mut repaired = Vec::with_capacity(bytes.len());
+    let mut repaired_symbols = 0;
+
+    for block in bytes.chunks(block_length) {
+        if block.len() <= parity * 2 {
+            return Err(DecodingError::Unrepairable);
+        }
+
+        // Invalid chars are read as zeros and repaired like any other damaged symbol.
+        let (data, parity_chars) = block.split_at(block.len() - parity * 2);
+        let value = |v: &u8| match *UTF8_TO_ENCODED_MAP.get(*v as usize).unwrap_or(&255) {
+            255 => 0,
+            v => v as u32,
+        };
+        let mut symbols: Vec<u32> = data.iter().map(value).collect();
+        symbols.extend(
+            parity_chars
+                .chunks_exact(2)
+                .map(|v| (value(&v[0]) * 60 + value(&v[1])) % FIELD),
+        );
+
+        repair_block(&mut symbols, parity)?;
+
+        let mut repaired_block = Vec::with_capacity(block.len());
+        for symbol in &symbols[..data.len()] {
+            if *symbol >= 60 {
+                return Err(DecodingError::Unrepairable);
+            }
+
+            repaired_block.push(ENCODED_TO_UTF8_MAP[*symbol as usize]);
+        }
+        repaired_block.extend(encode_parity_chars(&symbols[data.len()..]));
+
+        // Counts the symbols whose chars changed, including the invalid chars read as zeros.
+        repaired_symbols += data
+            .iter()
+            .zip(&repaired_block)
+            .filter(|(a, b)| a != b)
+            .count();
+        repaired_symbols += parity_chars
+            .chunks_exact(2)
+            .zip(repaired_block[data.len()..].chunks_exact(2))
+            .filter(|(a, b)| a != b)
+            .count();
+
+        repaired_block.truncate(data.len());
+        repaired.append(&mut repaired_block);
+    }
+
+    // SAFETY: the repaired string contains only ASCII characters.
+    let repaired = unsafe { String::from_utf8_unchecked(repaired) };
+
+    Ok((decode_g60(&repaired)?, repaired_symbols))
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn check_parity(parity: usize) {
+    assert!(
+        (1..MAX_BLOCK_SYMBOLS).contains(&parity),
+        "The parity must be between 1 and {}",
+        MAX_BLOCK_SYMBOLS - 1
+    );
+}
+
+fn encode_parity_chars(symbols: &[u32]) -> Vec<u8> {
+    symbols
+        .iter()
+        .flat_map(|v| {
+            [
+                ENCODED_TO_UTF8_MAP[(v / 60) as usize],
+                ENCODED_TO_UTF8_MAP[(v % 60) as usize],
+            ]
+        })
+        .collect()
+}
+
+#[inline(always)]
+fn mul(a: u32, b: u32) -> u32 {
+    a * b % FIELD
+}
+
+#[inline(always)]
+fn sub(a: u32, b: u32) -> u32 {
+    (a + FIELD - b) % FIELD
+}
+
+fn pow(mut base: u32, mut exponent: usize) -> u32 {
+    let mut result = 1;
+
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = mul(result, base);
+        }
+
+        base = mul(base, base);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+fn inv(a: u32) -> u32 {
+    pow(a, FIELD as usize - 2)
+}
+
+/// Evaluates a polynomial whose coefficients are sorted from the highest degree.
+fn eval(polynomial: &[u32], x: u32) -> u32 {
+    polynomial
+        .iter()
+        .fold(0, |result, v| (mul(result, x) + v) % FIELD)
+}
+
+/// Computes `(x - g^0) * (x - g^1) * ... * (x - g^(parity - 1))` with its coefficients
+/// sorted from the highest degree.
+fn generator_polynomial(parity: usize) -> Vec<u32> {
+    let mut result = vec![1];
+
+    for i in 0..parity {
+        let root = pow(GENERATOR, i);
+        let mut next = vec![0; result.len() + 1];
+
+        for (j, coefficient) in result.iter().enumerate() {
+            next[j] = (next[j] + coefficient) % FIELD;
+            next[j + 1] = sub(next[j + 1], mul(*coefficient, root));
+        }
+
+        result = next;
+    }
+
+    result
+}
+
+/// Computes the remainder of `data * x^parity` divided by `generator`, negated, so that
+/// appending it to `data` gives a multiple of `generator`.
+fn compute_parity(data: &[u32], generator: &[u32]) -> Vec<u32> {
+    let parity = generator.len() - 1;
+    let mut remainder = data.to_vec();
+    remainder.resize(data.len() + parity, 0);
+
+    for i in 0..data.len() {
+        let coefficient = remainder[i];
+
+        if coefficient != 0 {
+            for (j, v) in generator.iter().enumerate().skip(1) {
+                remainder[i + j] = sub(remainder[i + j], mul(*v, coefficient));
+            }
+        }
+    }
+
+    remainder[data.len()..].iter().map(|v| sub(0, *v)).collect()
+}
+
+/// Repairs the symbols of a block in place.
+fn repair_block(symbols: &mut [u32], parity: usize) -> Result<(), DecodingError> {
+    let syndromes: Vec<u32> = (0..parity)
+        .map(|i| eval(symbols, pow(GENERATOR, i)))
+        .collect();
+
+    if syndromes.iter().all(|v| *v == 0) {
+        return Ok(());
+    }
+
+    // Berlekamp–Massey, with the coefficients sorted from the lowest degree.
+    let mut locator = vec![1];
+    let mut previous = vec![1];
+    let mut errors = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = 1;
+
+    for n in 0..parity {
+        let discrepancy = (0..=errors.min(locator.len() - 1)).fold(0, |sum, i| {
+            (sum + mul(locator[i], syndromes[n - i])) % FIELD
+        });
+
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+
+        let factor = mul(discrepancy, inv(previous_discrepancy));
+        let mut next = locator.clone();
+        next.resize(next.len().max(previous.len() + shift), 0);
+        for (i, v) in previous.iter().enumerate() {
+            next[i + shift] = sub(next[i + shift], mul(factor, *v));
+        }
+
+        if 2 * errors <= n {
+            previous = std::mem::replace(&mut locator, next);
+            errors = n + 1 - errors;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            locator = next;
+            shift += 1;
+        }
+    }
+
+    if 2 * errors > parity {
+        return Err(DecodingError::Unrepairable);
+    }
+
+    // Error evaluator: syndromes * locator mod x^parity.
+    let evaluator: Vec<u32> = (0..parity)
+        .map(|i| {
+            (0..=i.min(locator.len() - 1)).fold(0, |sum, j| {
+                (sum + mul(locator[j], syndromes[i - j])) % FIELD
+            })
+        })
+        .collect();
+
+    // Chien search and Forney.
+    let eval_lowest = |polynomial: &[u32], x: u32| {
+        polynomial
+            .iter()
+            .rev()
+            .fold(0, |result, v| (mul(result, x) + v) % FIELD)
+    };
+    let derivative: Vec<u32> = locator
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, v)| mul(*v, i as u32 % FIELD))
+        .collect();
+
+    let mut found = 0;
+    for position in 0..symbols.len() {
+        // The symbol at `position` is the coefficient of x^degree.
+        let degree = symbols.len() - 1 - position;
+        let x = pow(GENERATOR, degree);
+        let x_inv = inv(x);
+
+        if eval_lowest(&locator, x_inv) != 0 {
+            continue;
+        }
+
+        let denominator = eval_lowest(&derivative, x_inv);
+        if denominator == 0 {
+            return Err(DecodingError::Unrepairable);
+        }
+
+        let magnitude = mul(mul(x, eval_lowest(&evaluator, x_inv)), inv(denominator));
+        symbols[position] = (symbols[position] + magnitude) % FIELD;
+        found += 1;
+    }
+
+    if found != locator.iter().rposition(|v| *v != 0).unwrap_or(0) {
+        return Err(DecodingError::Unrepairable);
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_generator_is_primitive() {
+        let mut values: Vec<u32> = (0..60).map(|i| pow(GENERATOR, i)).collect();
+        values.sort();
+        values.dedup();
+
+        assert_eq!(values.len(), 60, "Incorrect generator");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for length in [0, 1, 13, 100] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+
+            for parity in [1, 2, 4, 10, 59] {
+                let encoded = encode(&bytes, parity);
+
+                assert_eq!(
+                    decode(&encoded, parity),
+                    Ok((bytes.clone(), 0)),
+                    "Incorrect for '{}'",
+                    encoded
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_repair() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(100).collect();
+
+        for parity in [2, 4, 10] {
+            let encoded = encode(&bytes, parity).into_bytes();
+            let block_length = MAX_BLOCK_SYMBOLS - parity + parity * 2;
+
+            for _ in 0..200 {
+                // Damage `parity / 2` data chars of each block.
+                let mut test = encoded.clone();
+                let mut damaged = 0;
+                for block in test.chunks_mut(block_length) {
+                    let data_length = block.len() - parity * 2;
+
+                    for index in rand::seq::index::sample(&mut rng, data_length, parity / 2) {
+                        let previous = block[index];
+                        while block[index] == previous {
+                            block[index] = rng.gen_range(b'0'..=b'z');
+                        }
+
+                        damaged += 1;
+                    }
+                }
+                let test = String::from_utf8(test).unwrap();
+
+                assert_eq!(
+                    decode(&test, parity),
+                    Ok((bytes.clone(), damaged)),
+                    "Incorrect for '{}'",
+                    test
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unrepairable() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(13).collect();
+        let encoded = encode(&bytes, 2).into_bytes();
+
+        let mut test = encoded.clone();
+        test[0] = if test[0] == b'z' { b'y' } else { b'z' };
+        test[1] = if test[1] == b'z' { b'y' } else { b'z' };
+        let test = String::from_utf8(test).unwrap();
+
+        assert_ne!(decode(&test, 2), Ok((bytes, 2)), "Incorrect for '{}'", test);
+    }
+}
@@ -0,0 +1,272 @@
+use std::io::Write;
+
+use crate::encoding::compute_encoded_size;
+use crate::errors::EncodingError;
+use crate::streaming::EncoderWriter;
+
+/// The line ending inserted between the lines of a wrapped encoded string.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum LineEnding {
+    /// `\n`.
+    #[default]
+    Lf,
+    /// `\r\n`.
+    CrLf,
+}
+
+impl LineEnding {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the chars of the line ending.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// A configurable G60 encoder.
+///
+/// ```rust
+/// # use g60::EncodeConfig;
+/// let encoded = EncodeConfig::new().wrap(8).encode(b"Hello, world!");
+///
+/// assert_eq!(encoded, "Gt4CGFiH\nehzRzjCF\n16");
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct EncodeConfig {
+    wrap_width: Option<usize>,
+    line_ending: LineEnding,
+    prefix: String,
+    suffix: String,
+}
+
+impl EncodeConfig {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds a config that behaves like [encode](crate::encode).
+    pub fn new() -> EncodeConfig {
+        EncodeConfig {
+            wrap_width: None,
+            line_ending: LineEnding::Lf,
+            prefix: String::new(),
+            suffix: String::new(),
+        }
+    }
+
+    // SETTERS ----------------------------------------------------------------
+
+    /// Splits the encoded string in lines of `width` chars. The last line does not end
+    /// with a line ending.
+    ///
+    /// # Panics
+    /// Panics if `width` is 0.
+    pub fn wrap(mut self, width: usize) -> Self {
+        assert_ne!(width, 0, "The wrap width cannot be 0");
+
+        self.wrap_width = Some(width);
+        self
+    }
+
+    /// Sets the line ending used to wrap the encoded string.
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Sets a tag written before the encoded string, e.g. `g60:`, so it can be told apart
+    /// from other encodings. It is not taken into account to wrap the lines.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Sets a tag written after the encoded string.
+    /// It is not taken into account to wrap the lines.
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Encodes a list of bytes into a G60 encoding format following this config.
+    pub fn encode(&self, content: &[u8]) -> String {
+        let mut slice = Vec::with_capacity(self.compute_encoded_size(content.len()));
+
+        self.encode_in_writer(content, &mut slice).unwrap();
+
+        // SAFETY: the encoded string contains only ASCII characters.
+        unsafe { String::from_utf8_unchecked(slice) }
+    }
+
+    /// Encodes a list of bytes into a G60 encoding format following this config.
+    /// The result is written in `writer`.
+    ///
+    /// # Errors
+    /// An error will be thrown if the writing process fails.
+    pub fn encode_in_writer<T: Write>(
+        &self,
+        content: &[u8],
+        writer: &mut T,
+    ) -> Result<usize, EncodingError> {
+        let mut encoder = EncoderWriter::with_config(writer, self.clone());
+        encoder.write_all(content)?;
+        encoder.finish()?;
+
+        Ok(self.compute_encoded_size(content.len()))
+    }
+
+    // AUX METHODS ------------------------------------------------------------
+
+    /// Computes the length of the encoded string including the line endings.
+    fn compute_encoded_size(&self, content_length: usize) -> usize {
+        let encoded_size = compute_encoded_size(content_length);
+        let tags_size = self.prefix.len() + self.suffix.len();
+
+        match self.wrap_width {
+            Some(width) if encoded_size != 0 => {
+                encoded_size
+                    + (encoded_size - 1) / width * self.line_ending.as_str().len()
+                    + tags_size
+            }
+            _ => encoded_size + tags_size,
+        }
+    }
+
+    pub(crate) fn into_tags(self) -> (String, String) {
+        (self.prefix, self.suffix)
+    }
+
+    pub(crate) fn line_wrapper(&self) -> LineWrapper {
+        LineWrapper {
+            width: self.wrap_width,
+            line_ending: self.line_ending,
+            column: 0,
+        }
+    }
+}
+
+/// Inserts the line endings of an encoded string written in pieces.
+#[derive(Debug)]
+pub(crate) struct LineWrapper {
+    width: Option<usize>,
+    line_ending: LineEnding,
+    column: usize,
+}
+
+impl LineWrapper {
+    /// Writes `encoded` into `writer` inserting the line endings, returning the number of
+    /// elements written. Line endings are only written before a new char, so the result
+    /// never ends with one.
+    pub(crate) fn write<W: Write>(
+        &mut self,
+        writer: &mut W,
+        mut encoded: &[u8],
+    ) -> std::io::Result<usize> {
+        let Some(width) = self.width else {
+            writer.write_all(encoded)?;
+            return Ok(encoded.len());
+        };
+
+        let mut written = 0;
+        while !encoded.is_empty() {
+            if self.column == width {
+                let line_ending = self.line_ending.as_str().as_bytes();
+
+                writer.write_all(line_ending)?;
+                written += line_ending.len();
+                self.column = 0;
+            }
+
+            let length = (width - self.column).min(encoded.len());
+            writer.write_all(&encoded[..length])?;
+            written += length;
+            self.column += length;
+            encoded = &encoded[length..];
+        }
+
+        Ok(written)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode;
+
+    #[test]
+    fn test_default_config_matches_encode() {
+        for length in [0, 1, 7, 8, 13, 1000] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+
+            assert_eq!(
+                EncodeConfig::new().encode(&bytes),
+                encode(&bytes),
+                "Incorrect for {length}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tags() {
+        let config = EncodeConfig::new().prefix("g60:").suffix(";").wrap(8);
+
+        for (test, expected) in [
+            (&b""[..], "g60:;"),
+            (b"Hello, world!", "g60:Gt4CGFiH\nehzRzjCF\n16;"),
+        ] {
+            assert_eq!(config.encode(test), expected, "Incorrect for {:?}", test);
+
+            let mut writer = Vec::new();
+            let written = config
+                .encode_in_writer(test, &mut writer)
+                .expect("The encoding must succeed");
+            assert_eq!(written, expected.len(), "Incorrect for {:?}", test);
+            assert_eq!(writer, expected.as_bytes(), "Incorrect for {:?}", test);
+        }
+    }
+
+    #[test]
+    fn test_wrap() {
+        for length in [0, 1, 7, 8, 13, 1000] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = encode(&bytes);
+
+            for width in [1, 11, 64, 76] {
+                for line_ending in [LineEnding::Lf, LineEnding::CrLf] {
+                    let config = EncodeConfig::new().wrap(width).line_ending(line_ending);
+                    let expected = encoded
+                        .as_bytes()
+                        .chunks(width)
+                        .map(|v| std::str::from_utf8(v).unwrap())
+                        .collect::<Vec<_>>()
+                        .join(line_ending.as_str());
+
+                    let result = config.encode(&bytes);
+                    assert_eq!(result, expected, "Incorrect for {length} and {width}");
+
+                    let mut writer = Vec::new();
+                    let written = config
+                        .encode_in_writer(&bytes, &mut writer)
+                        .expect("The encoding must succeed");
+                    assert_eq!(
+                        written,
+                        expected.len(),
+                        "Incorrect for {length} and {width}"
+                    );
+                    assert_eq!(
+                        writer,
+                        expected.as_bytes(),
+                        "Incorrect for {length} and {width}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,18 +1,81 @@
+use std::borrow::Borrow;
 use std::io::Write;
 
-use crate::constants::ENCODED_TO_UTF8_MAP;
+use crate::constants::{BLOCK_GROUPS, ENCODED_PAIR_TO_UTF8_MAP, ENCODED_TO_UTF8_MAP};
+use crate::dispatch;
 use crate::errors::EncodingError;
-use crate::utils::div_rem;
+use crate::utils::{div_rem, scratch_buffer};
 
 /// Encodes a list of bytes into a G60 encoding format.
 pub fn encode(content: &[u8]) -> String {
+    // Fast path for inputs shorter than a group.
+    if content.len() < 8 {
+        if content.is_empty() {
+            return String::new();
+        }
+
+        let encoded = compute_chunk(content);
+        let encoded = &encoded[..compute_encoded_size(content.len())];
+
+        // SAFETY: the encoded string contains only ASCII characters.
+        return unsafe { String::from_utf8_unchecked(encoded.to_vec()) };
+    }
+
     let mut slice = Vec::with_capacity(compute_encoded_size(content.len()));
 
     encode_in_writer(content, &mut slice).unwrap();
 
+    // SAFETY: the encoded string contains only ASCII characters.
     unsafe { String::from_utf8_unchecked(slice) }
 }
 
+/// Encodes a list of bytes into a G60 encoding format.
+/// The result is appended to `string` and returns the number of elements appended.
+pub fn encode_into(content: &[u8], string: &mut String) -> usize {
+    string.reserve_exact(compute_encoded_size(content.len()));
+
+    // SAFETY: the encoded string contains only ASCII characters.
+    encode_in_writer(content, unsafe { string.as_mut_vec() }).unwrap()
+}
+
+/// Encodes the bytes produced by `content` into a G60 encoding format, one group at a
+/// time, so lazily produced data does not need to be collected first.
+///
+/// ```rust
+/// let encoded = g60::encode_iter(b"Hello, world!".iter());
+///
+/// assert_eq!(encoded, "Gt4CGFiHehzRzjCF16");
+/// ```
+pub fn encode_iter<I>(content: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Borrow<u8>,
+{
+    let content = content.into_iter();
+    let mut result = Vec::with_capacity(compute_encoded_size(content.size_hint().0));
+    let mut group = scratch_buffer::<8>();
+    let mut group_length = 0;
+
+    for byte in content {
+        group[group_length] = *byte.borrow();
+        group_length += 1;
+
+        if group_length == 8 {
+            result.extend_from_slice(&compute_chunk(&group[..]));
+            group_length = 0;
+        }
+    }
+
+    // Last incomplete group.
+    if group_length != 0 {
+        let encoded = compute_chunk(&group[..group_length]);
+        result.extend_from_slice(&encoded[..compute_encoded_size(group_length)]);
+    }
+
+    // SAFETY: the encoded string contains only ASCII characters.
+    unsafe { String::from_utf8_unchecked(result) }
+}
+
 /// Encodes a list of bytes into a G60 encoding format.
 /// The result is placed into `slice` and returns the number of elements written.
 ///
@@ -28,7 +91,35 @@ pub fn encode_in_slice(content: &[u8], slice: &mut [u8]) -> Result<usize, Encodi
         });
     }
 
-    encode_in_writer(content, &mut std::io::Cursor::new(slice))
+    // Complete groups.
+    let complete_groups_length = content.len() >> 3 << 3;
+    let (complete_slice, last_slice) =
+        slice[..required_slice_size].split_at_mut((complete_groups_length >> 3) * 11);
+    dispatch::encode_groups(&content[..complete_groups_length], complete_slice);
+
+    // Last incomplete group.
+    if !last_slice.is_empty() {
+        let encoded = compute_chunk(&content[complete_groups_length..]);
+
+        last_slice.copy_from_slice(&encoded[..last_slice.len()]);
+    }
+
+    Ok(required_slice_size)
+}
+
+/// Encodes a list of bytes into a G60 encoding format.
+/// The result is placed into `slice` and returns the written region as a string.
+///
+/// # Errors
+/// An error will be thrown if `slice` does not have enough space to store the encoded string.
+pub fn encode_in_slice_str<'a>(
+    content: &[u8],
+    slice: &'a mut [u8],
+) -> Result<&'a str, EncodingError> {
+    let written = encode_in_slice(content, slice)?;
+
+    // SAFETY: the encoded string contains only ASCII characters.
+    Ok(unsafe { std::str::from_utf8_unchecked(&slice[..written]) })
 }
 
 /// Encodes a list of bytes into a G60 encoding format.
@@ -40,10 +131,13 @@ pub fn encode_in_writer<T: Write>(content: &[u8], writer: &mut T) -> Result<usiz
     let required_slice_size = compute_encoded_size(content.len());
 
     // Complete groups.
-    for chunk in content.chunks_exact(8) {
-        let encoded = compute_chunk(chunk);
+    let mut buffer = scratch_buffer::<{ BLOCK_GROUPS * 11 }>();
+    let complete_groups_length = content.len() >> 3 << 3;
+    for block in content[..complete_groups_length].chunks(BLOCK_GROUPS * 8) {
+        let encoded = &mut buffer[..(block.len() >> 3) * 11];
+        dispatch::encode_groups(block, encoded);
 
-        writer.write_all(&encoded)?;
+        writer.write_all(encoded)?;
     }
 
     // Last incomplete group.
@@ -69,7 +163,13 @@ pub(crate) fn compute_encoded_size(content_length: usize) -> usize {
     (11 * content_length + 7) >> 3
 }
 
-#[inline]
+/// Like [compute_encoded_size] but returns `None` if the result does not fit in a `usize`.
+#[inline(always)]
+pub(crate) fn checked_compute_encoded_size(content_length: usize) -> Option<usize> {
+    Some(content_length.checked_mul(11)?.checked_add(7)? >> 3)
+}
+
+#[inline(always)]
 pub(crate) fn compute_chunk(chunk: &[u8]) -> [u8; 11] {
     let c_a = chunk[0] as usize;
     let c_b = *chunk.get(1).unwrap_or(&0) as usize;
@@ -81,32 +181,26 @@ pub(crate) fn compute_chunk(chunk: &[u8]) -> [u8; 11] {
     let c_h = *chunk.get(7).unwrap_or(&0) as usize;
 
     let (c2, r2) = div_rem(c_b, 20);
-    let (c1, r1) = div_rem(14 * c_a + c2, 60);
     let (c3, r3) = div_rem(c_c, 90);
     let b3h = c_d >> 7;
     let b3l = c_d & 0x7F;
     let (c4, r4) = div_rem((r3 << 1) + b3h, 3);
     let (c6, r6) = div_rem(c_e, 30);
-    let (c5, r5) = div_rem(9 * b3l + c6, 60);
     let (c7, r7) = div_rem(c_f, 150);
     let (c8a, r8a) = div_rem(c_g, 144);
-    let (c8, r8) = div_rem((r7 << 1) + c8a, 5);
     let (c9, r9) = div_rem(r8a, 12);
-    let (c10, r10) = div_rem(c_h, 60);
-
-    [
-        ENCODED_TO_UTF8_MAP[c1],
-        ENCODED_TO_UTF8_MAP[r1],
-        ENCODED_TO_UTF8_MAP[3 * r2 + c3],
-        ENCODED_TO_UTF8_MAP[c4],
-        ENCODED_TO_UTF8_MAP[20 * r4 + c5],
-        ENCODED_TO_UTF8_MAP[r5],
-        ENCODED_TO_UTF8_MAP[(r6 << 1) + c7],
-        ENCODED_TO_UTF8_MAP[c8],
-        ENCODED_TO_UTF8_MAP[12 * r8 + c9],
-        ENCODED_TO_UTF8_MAP[5 * r9 + c10],
-        ENCODED_TO_UTF8_MAP[r10],
-    ]
+
+    // Pairs of chars are emitted from their combined value `60 * c0 + c1`.
+    let mut result = [0; 11];
+    result[0..2].copy_from_slice(&ENCODED_PAIR_TO_UTF8_MAP[14 * c_a + c2]);
+    result[2] = ENCODED_TO_UTF8_MAP[3 * r2 + c3];
+    result[3] = ENCODED_TO_UTF8_MAP[c4];
+    result[4..6].copy_from_slice(&ENCODED_PAIR_TO_UTF8_MAP[1200 * r4 + 9 * b3l + c6]);
+    result[6] = ENCODED_TO_UTF8_MAP[(r6 << 1) + c7];
+    result[7..9].copy_from_slice(&ENCODED_PAIR_TO_UTF8_MAP[12 * ((r7 << 1) + c8a) + c9]);
+    result[9..11].copy_from_slice(&ENCODED_PAIR_TO_UTF8_MAP[300 * r9 + c_h]);
+
+    result
 }
 
 // ----------------------------------------------------------------------------
@@ -131,6 +225,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_into() {
+        let mut result = "user:".to_string();
+        let encoded_chars = encode_into(b"Hello, world!", &mut result);
+
+        assert_eq!(encoded_chars, 18, "Incorrect chars");
+        assert_eq!(result, "user:Gt4CGFiHehzRzjCF16", "Incorrect result");
+    }
+
+    #[test]
+    fn test_encode_iter() {
+        for length in [0, 1, 7, 8, 13, 100] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+
+            assert_eq!(
+                encode_iter(bytes.iter().copied()),
+                encode(&bytes),
+                "Incorrect for length {}",
+                length
+            );
+            assert_eq!(
+                encode_iter(&bytes),
+                encode(&bytes),
+                "Incorrect for length {}",
+                length
+            );
+        }
+    }
+
     #[test]
     fn test_encode_in_writer() {
         let test = "Hello, world!";
@@ -172,6 +295,29 @@ mod tests {
         assert_eq!(result_slice, result, "Incorrect slice result");
     }
 
+    #[test]
+    fn test_encode_in_slice_str() {
+        let test = "Hello, world!";
+        let mut result_slice = vec![0; 20];
+        let result = encode_in_slice_str(test.as_bytes(), &mut result_slice)
+            .expect("The encoding must succeed");
+
+        assert_eq!(result, "Gt4CGFiHehzRzjCF16", "Incorrect result");
+
+        let error = encode_in_slice_str(test.as_bytes(), &mut result_slice[..15])
+            .expect_err("The encoding cannot succeed");
+
+        assert_eq!(
+            error,
+            EncodingError::NotEnoughSpaceInSlice {
+                actual: 15,
+                required: 18,
+            },
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
     #[test]
     fn test_encode_in_slice_shorter_slice() {
         let test = "Hello, world!";
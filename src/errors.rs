@@ -2,10 +2,15 @@ use std::fmt::{Debug, Display, Formatter};
 
 /// A wrapping error of all possible errors of the G60 encoding library.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     Encoding(EncodingError),
     Decoding(DecodingError),
     Verification(VerificationError),
+    Canonicalization(CanonicalizationError),
+    FileVerification(FileVerificationError),
+    Alphabet(AlphabetError),
+    Pipeline(PipelineError),
 }
 
 impl Display for Error {
@@ -14,6 +19,10 @@ impl Display for Error {
             Error::Encoding(e) => Display::fmt(&e, f),
             Error::Decoding(e) => Display::fmt(&e, f),
             Error::Verification(e) => Display::fmt(&e, f),
+            Error::Canonicalization(e) => Display::fmt(&e, f),
+            Error::FileVerification(e) => Display::fmt(&e, f),
+            Error::Alphabet(e) => Display::fmt(&e, f),
+            Error::Pipeline(e) => Display::fmt(&e, f),
         }
     }
 }
@@ -26,11 +35,18 @@ impl std::error::Error for Error {}
 
 /// An error returned during the encoding process.
 #[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum EncodingError {
     /// The result buffer has not enough space to held the encoding result.
     NotEnoughSpaceInSlice { actual: usize, required: usize },
     /// A writer error.
     WritingError(std::io::ErrorKind),
+    /// A reader error.
+    ReadingError(std::io::ErrorKind),
+    /// The value needs more chars than the requested fixed width.
+    WidthExceeded { width: usize, required: usize },
+    /// The value is out of the range supported by the encoding.
+    OutOfRange,
 }
 
 impl Display for EncodingError {
@@ -59,12 +75,43 @@ impl From<std::io::Error> for EncodingError {
 
 /// An error returned during the decoding process.
 #[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum DecodingError {
     /// A verification error over the encoded string.
     Verification(VerificationError),
 
     /// The result buffer has not enough space to held the decoding result.
     NotEnoughSpaceInSlice { actual: usize, required: usize },
+
+    /// The decoded value is out of the range of the type it is decoded into.
+    OutOfRange,
+
+    /// The decoding result exceeds the configured output limit.
+    OutputLimitExceeded { limit: usize, required: usize },
+
+    /// The decoding result does not have the expected length.
+    UnexpectedDecodedLength { expected: usize, actual: usize },
+
+    /// The decoded bits do not end with a valid padding.
+    InvalidBitPadding,
+
+    /// The encoded string does not start with the required prefix.
+    MissingPrefix,
+
+    /// The encoded string does not end with the required suffix.
+    MissingSuffix,
+
+    /// The checksum of the encoded string does not match its content.
+    ChecksumMismatch,
+
+    /// The encoded string has more damaged symbols than its parity symbols can repair.
+    Unrepairable,
+
+    /// A writer error.
+    WritingError(std::io::ErrorKind),
+
+    /// A reader error.
+    ReadingError(std::io::ErrorKind),
 }
 
 impl Display for DecodingError {
@@ -92,7 +139,8 @@ impl From<DecodingError> for Error {
 // ----------------------------------------------------------------------------
 
 /// An error returned during the verification process.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum VerificationError {
     /// The length of the encoded string is incorrect.
     InvalidLength,
@@ -115,3 +163,155 @@ impl From<VerificationError> for Error {
         Self::Verification(v)
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An error returned during the canonicalization process.
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CanonicalizationError {
+    /// A verification error over the encoded string.
+    Verification(VerificationError),
+    /// A reader error.
+    ReadingError(std::io::ErrorKind),
+    /// A writer error.
+    WritingError(std::io::ErrorKind),
+}
+
+impl Display for CanonicalizationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for CanonicalizationError {}
+
+impl From<VerificationError> for CanonicalizationError {
+    fn from(v: VerificationError) -> Self {
+        Self::Verification(v)
+    }
+}
+
+impl From<CanonicalizationError> for Error {
+    fn from(v: CanonicalizationError) -> Self {
+        Self::Canonicalization(v)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An error returned during the verification of a file.
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum FileVerificationError {
+    /// The content of the file is not a valid G60 encoded string. `offset` is the position
+    /// of the invalid byte or, for the other errors, of the group where it was found.
+    Verification {
+        offset: usize,
+        error: VerificationError,
+    },
+    /// A reader error.
+    ReadingError(std::io::ErrorKind),
+}
+
+impl Display for FileVerificationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for FileVerificationError {}
+
+impl From<std::io::Error> for FileVerificationError {
+    fn from(v: std::io::Error) -> Self {
+        Self::ReadingError(v.kind())
+    }
+}
+
+impl From<FileVerificationError> for Error {
+    fn from(v: FileVerificationError) -> Self {
+        Self::FileVerification(v)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An error returned while building an alphabet.
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AlphabetError {
+    /// The alphabet does not have exactly 60 chars.
+    InvalidLength { length: usize },
+    /// A char of the alphabet is not ASCII.
+    InvalidByte { index: usize, byte: u8 },
+    /// A char of the alphabet is repeated.
+    RepeatedByte { index: usize, byte: u8 },
+}
+
+impl Display for AlphabetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for AlphabetError {}
+
+impl From<AlphabetError> for Error {
+    fn from(v: AlphabetError) -> Self {
+        Self::Alphabet(v)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An error returned by the threaded reader-to-writer pipelines. Unlike the other errors,
+/// it keeps the full I/O errors of the streams, including their messages.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PipelineError {
+    /// A verification error over the encoded string.
+    Verification(VerificationError),
+    /// A reader error.
+    Reading(std::io::Error),
+    /// A writer error.
+    Writing(std::io::Error),
+}
+
+impl Display for PipelineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PipelineError::Verification(e) => Display::fmt(e, f),
+            PipelineError::Reading(e) => write!(f, "Reading error: {}", e),
+            PipelineError::Writing(e) => write!(f, "Writing error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PipelineError::Verification(e) => Some(e),
+            PipelineError::Reading(e) | PipelineError::Writing(e) => Some(e),
+        }
+    }
+}
+
+impl From<VerificationError> for PipelineError {
+    fn from(v: VerificationError) -> Self {
+        Self::Verification(v)
+    }
+}
+
+impl From<PipelineError> for Error {
+    fn from(v: PipelineError) -> Self {
+        Self::Pipeline(v)
+    }
+}
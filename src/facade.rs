@@ -0,0 +1,163 @@
+use crate::decoding::compute_decoded_size;
+use crate::encoding::compute_encoded_size;
+use crate::errors::{DecodingError, VerificationError};
+use crate::{decode, decode_in_slice, encode, encode_in_slice, encode_into};
+
+/// The G60 encoding exposed through the same surface as the encodings of the
+/// [data-encoding](https://crates.io/crates/data-encoding) crate, so code written against
+/// that interface can use G60 too.
+pub const G60: Encoding = Encoding;
+
+/// An adapter of the G60 encoding with the interface of the encodings of the
+/// [data-encoding](https://crates.io/crates/data-encoding) crate. See [G60].
+///
+/// ```rust
+/// # use g60::G60;
+/// let encoded = G60.encode(b"Hello, world!");
+///
+/// assert_eq!(encoded, "Gt4CGFiHehzRzjCF16");
+/// assert_eq!(G60.decode(encoded.as_bytes()).unwrap(), b"Hello, world!");
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct Encoding;
+
+impl Encoding {
+    // METHODS ----------------------------------------------------------------
+
+    /// Returns the encoded length of an input of length `len`.
+    pub fn encode_len(&self, len: usize) -> usize {
+        compute_encoded_size(len)
+    }
+
+    /// Encodes `input` into `output`.
+    ///
+    /// # Panics
+    /// Panics if `output.len()` is not [encode_len](Encoding::encode_len) of `input.len()`.
+    pub fn encode_mut(&self, input: &[u8], output: &mut [u8]) {
+        assert_eq!(
+            output.len(),
+            self.encode_len(input.len()),
+            "Incorrect output length"
+        );
+
+        encode_in_slice(input, output).unwrap();
+    }
+
+    /// Appends the encoding of `input` to `output`.
+    pub fn encode_append(&self, input: &[u8], output: &mut String) {
+        encode_into(input, output);
+    }
+
+    /// Returns the encoding of `input`.
+    pub fn encode(&self, input: &[u8]) -> String {
+        encode(input)
+    }
+
+    /// Returns the decoded length of an input of length `len`.
+    ///
+    /// # Errors
+    /// An error will be thrown if `len` is not a valid length of a G60 encoded string.
+    pub fn decode_len(&self, len: usize) -> Result<usize, DecodingError> {
+        if let 1 | 4 | 8 = len % 11 {
+            return Err(DecodingError::Verification(
+                VerificationError::InvalidLength,
+            ));
+        }
+
+        Ok(compute_decoded_size(len))
+    }
+
+    /// Decodes `input` into `output`, returning the number of elements written.
+    ///
+    /// # Errors
+    /// An error will be thrown if `input` is not a valid canonical G60 encoded string.
+    ///
+    /// # Panics
+    /// Panics if `output.len()` is not [decode_len](Encoding::decode_len) of `input.len()`.
+    pub fn decode_mut(&self, input: &[u8], output: &mut [u8]) -> Result<usize, DecodingError> {
+        let decoded_length = self.decode_len(input.len())?;
+        assert_eq!(output.len(), decoded_length, "Incorrect output length");
+
+        decode_in_slice(to_str(input)?, output)
+    }
+
+    /// Returns the decoding of `input`.
+    ///
+    /// # Errors
+    /// An error will be thrown if `input` is not a valid canonical G60 encoded string.
+    pub fn decode(&self, input: &[u8]) -> Result<Vec<u8>, DecodingError> {
+        decode(to_str(input)?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Converts `input` into a string, failing at the first byte outside the ASCII range,
+/// which is never valid in a G60 encoded string anyway.
+fn to_str(input: &[u8]) -> Result<&str, DecodingError> {
+    std::str::from_utf8(input).map_err(|e| {
+        let index = e.valid_up_to();
+
+        DecodingError::Verification(VerificationError::InvalidByte {
+            index,
+            byte: input[index],
+        })
+    })
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoding() {
+        let test = b"Hello, world!";
+        let encoded = "Gt4CGFiHehzRzjCF16";
+
+        assert_eq!(G60.encode_len(test.len()), 18);
+        assert_eq!(G60.encode(test), encoded);
+
+        let mut output = vec![0; 18];
+        G60.encode_mut(test, &mut output);
+        assert_eq!(output, encoded.as_bytes());
+
+        let mut output = "user:".to_string();
+        G60.encode_append(test, &mut output);
+        assert_eq!(output, "user:Gt4CGFiHehzRzjCF16");
+    }
+
+    #[test]
+    fn test_decoding() {
+        let test = b"Gt4CGFiHehzRzjCF16";
+        let decoded = b"Hello, world!";
+
+        assert_eq!(G60.decode_len(test.len()), Ok(13));
+        assert_eq!(G60.decode(test), Ok(decoded.to_vec()));
+
+        let mut output = vec![0; 13];
+        assert_eq!(G60.decode_mut(test, &mut output), Ok(13));
+        assert_eq!(output, decoded);
+
+        assert_eq!(
+            G60.decode_len(19),
+            Err(DecodingError::Verification(
+                VerificationError::InvalidLength
+            ))
+        );
+        assert_eq!(
+            G60.decode(b"Gt4\xFFGFiHehzRzjCF16"),
+            Err(DecodingError::Verification(
+                VerificationError::InvalidByte {
+                    index: 3,
+                    byte: 0xFF,
+                }
+            ))
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use std::io::{ErrorKind, Read, Write};
+
+use crate::constants::{ENCODED_TO_UTF8_MAP, UTF8_TO_ENCODED_MAP};
+use crate::encoding::checked_compute_encoded_size;
+use crate::errors::{DecodingError, EncodingError, VerificationError};
+use crate::{decode, encode_in_writer};
+
+/// Values of a length char that are followed by more length chars.
+const CONTINUATION: u8 = 30;
+
+/// Writes `content` into `writer` as a frame: its length as a G60 varint followed by its
+/// G60 encoding, so several frames can be concatenated in one stream and read back with
+/// [read_framed]. Returns the number of chars written.
+///
+/// The length is written in base 30, least significant digit first, where the chars of
+/// values `0..30` are the last digit and the chars of values `30..60` are digits followed
+/// by more digits.
+///
+/// ```rust
+/// let mut stream = Vec::new();
+/// g60::write_framed(&mut stream, b"Hello").unwrap();
+/// g60::write_framed(&mut stream, b", world!").unwrap();
+///
+/// let mut reader = stream.as_slice();
+/// assert_eq!(g60::read_framed(&mut reader).unwrap(), Some(b"Hello".to_vec()));
+/// assert_eq!(g60::read_framed(&mut reader).unwrap(), Some(b", world!".to_vec()));
+/// assert_eq!(g60::read_framed(&mut reader).unwrap(), None);
+/// ```
+///
+/// # Errors
+/// An error will be thrown if the writing process fails.
+pub fn write_framed<W: Write>(writer: &mut W, content: &[u8]) -> Result<usize, EncodingError> {
+    let mut length = content.len();
+    let mut prefix = Vec::with_capacity(13);
+
+    loop {
+        let digit = (length % CONTINUATION as usize) as u8;
+        length /= CONTINUATION as usize;
+
+        if length == 0 {
+            prefix.push(ENCODED_TO_UTF8_MAP[digit as usize]);
+            break;
+        }
+
+        prefix.push(ENCODED_TO_UTF8_MAP[(digit + CONTINUATION) as usize]);
+    }
+
+    writer.write_all(&prefix)?;
+    let written = encode_in_writer(content, writer)?;
+
+    Ok(prefix.len() + written)
+}
+
+/// Reads the next frame written by [write_framed] from `reader`, returning `None` if the
+/// reader is at its end.
+///
+/// The length is read one byte at a time, so prefer a buffered reader. The payload is
+/// read incrementally, so a frame declaring more content than the reader has does not
+/// allocate it up front, but it is not bounded either: use [read_framed_with_limit] for
+/// untrusted input.
+///
+/// # Errors
+/// An error will be thrown in the following cases:
+/// - if the reading process fails or the reader ends in the middle of a frame.
+/// - if the frame is not valid. The indexes of the errors are relative to the start of the
+///   frame.
+pub fn read_framed<R: Read>(reader: &mut R) -> Result<Option<Vec<u8>>, DecodingError> {
+    read_framed_with_limit(reader, usize::MAX)
+}
+
+/// Reads the next frame written by [write_framed] from `reader` like [read_framed], but
+/// rejects the frame before reading its payload if its declared length is greater than
+/// `max_length` bytes.
+///
+/// ```rust
+/// let mut stream = Vec::new();
+/// g60::write_framed(&mut stream, b"Hello, world!").unwrap();
+///
+/// assert!(g60::read_framed_with_limit(&mut stream.as_slice(), 12).is_err());
+/// assert_eq!(
+///     g60::read_framed_with_limit(&mut stream.as_slice(), 13).unwrap(),
+///     Some(b"Hello, world!".to_vec())
+/// );
+/// ```
+///
+/// # Errors
+/// An error will be thrown in the following cases:
+/// - if the reading process fails or the reader ends in the middle of a frame.
+/// - if the declared length of the frame is greater than `max_length`.
+/// - if the frame is not valid. The indexes of the errors are relative to the start of the
+///   frame.
+pub fn read_framed_with_limit<R: Read>(
+    reader: &mut R,
+    max_length: usize,
+) -> Result<Option<Vec<u8>>, DecodingError> {
+    // Length.
+    let mut length = 0usize;
+    let mut prefix_length = 0;
+    let mut byte = [0];
+
+    loop {
+        match reader.read(&mut byte) {
+            Ok(0) if prefix_length == 0 => return Ok(None),
+            Ok(0) => return Err(DecodingError::ReadingError(ErrorKind::UnexpectedEof)),
+            Ok(_) => {}
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(DecodingError::ReadingError(e.kind())),
+        }
+
+        let invalid_byte = VerificationError::InvalidByte {
+            index: prefix_length,
+            byte: byte[0],
+        };
+        let value = *UTF8_TO_ENCODED_MAP.get(byte[0] as usize).unwrap_or(&255);
+        if value == 255 {
+            return Err(invalid_byte.into());
+        }
+
+        let digit = (value % CONTINUATION) as usize;
+        let weight = (CONTINUATION as usize)
+            .checked_pow(prefix_length as u32)
+            .ok_or(invalid_byte)?;
+        length = weight
+            .checked_mul(digit)
+            .and_then(|v| v.checked_add(length))
+            .ok_or(invalid_byte)?;
+        prefix_length += 1;
+
+        if value < CONTINUATION {
+            // A zero last digit after other digits has a shorter representation.
+            if value == 0 && prefix_length > 1 {
+                return Err(VerificationError::NotCanonical.into());
+            }
+
+            break;
+        }
+    }
+
+    if length > max_length {
+        return Err(DecodingError::OutputLimitExceeded {
+            limit: max_length,
+            required: length,
+        });
+    }
+
+    // Payload.
+    let encoded_length =
+        checked_compute_encoded_size(length).ok_or(VerificationError::InvalidByte {
+            index: prefix_length - 1,
+            byte: byte[0],
+        })?;
+    let mut payload = Vec::new();
+    if let Err(e) = reader.take(encoded_length as u64).read_to_end(&mut payload) {
+        return Err(DecodingError::ReadingError(e.kind()));
+    }
+
+    if payload.len() != encoded_length {
+        return Err(DecodingError::ReadingError(ErrorKind::UnexpectedEof));
+    }
+
+    let payload = std::str::from_utf8(&payload).map_err(|e| {
+        let index = e.valid_up_to();
+
+        DecodingError::Verification(VerificationError::InvalidByte {
+            index: prefix_length + index,
+            byte: payload[index],
+        })
+    })?;
+
+    match decode(payload) {
+        Ok(v) => Ok(Some(v)),
+        Err(DecodingError::Verification(VerificationError::InvalidByte { index, byte })) => Err(
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: prefix_length + index,
+                byte,
+            }),
+        ),
+        Err(e) => Err(e),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_framed() {
+        let mut stream = Vec::new();
+        let written =
+            write_framed(&mut stream, b"Hello, world!").expect("The encoding must succeed");
+
+        assert_eq!(written, 19);
+        assert_eq!(stream, b"DGt4CGFiHehzRzjCF16");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let lengths = [0, 1, 13, 29, 30, 899, 900, 1000];
+        let mut stream = Vec::new();
+
+        for length in lengths {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            write_framed(&mut stream, &bytes).expect("The encoding must succeed");
+        }
+
+        let mut reader = stream.as_slice();
+        for length in lengths {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+
+            assert_eq!(
+                read_framed(&mut reader),
+                Ok(Some(bytes)),
+                "Incorrect for length {}",
+                length
+            );
+        }
+
+        assert_eq!(read_framed(&mut reader), Ok(None));
+    }
+
+    #[test]
+    fn test_read_framed_errors() {
+        for (test, error) in [
+            ("WD", DecodingError::ReadingError(ErrorKind::UnexpectedEof)),
+            (
+                "DGt4CGFiHehzRzjCF1",
+                DecodingError::ReadingError(ErrorKind::UnexpectedEof),
+            ),
+            ("W", DecodingError::ReadingError(ErrorKind::UnexpectedEof)),
+            (
+                "W0",
+                DecodingError::Verification(VerificationError::NotCanonical),
+            ),
+            (
+                "DGt4CGFiHehzRzjCF1O",
+                DecodingError::Verification(VerificationError::InvalidByte {
+                    index: 18,
+                    byte: b'O',
+                }),
+            ),
+            (
+                "zzzzzzzzzzzzzz1",
+                DecodingError::Verification(VerificationError::InvalidByte {
+                    index: 13,
+                    byte: b'z',
+                }),
+            ),
+            (
+                "-",
+                DecodingError::Verification(VerificationError::InvalidByte {
+                    index: 0,
+                    byte: b'-',
+                }),
+            ),
+            (
+                "zzzzzzzzzzzzK",
+                DecodingError::Verification(VerificationError::InvalidByte {
+                    index: 12,
+                    byte: b'K',
+                }),
+            ),
+            (
+                "WWWWWWWL",
+                DecodingError::ReadingError(ErrorKind::UnexpectedEof),
+            ),
+        ] {
+            assert_eq!(
+                read_framed(&mut test.as_bytes()),
+                Err(error),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_framed_with_limit() {
+        let mut stream = Vec::new();
+        write_framed(&mut stream, b"Hello, world!").expect("The encoding must succeed");
+
+        assert_eq!(
+            read_framed_with_limit(&mut stream.as_slice(), 13),
+            Ok(Some(b"Hello, world!".to_vec()))
+        );
+        assert_eq!(
+            read_framed_with_limit(&mut stream.as_slice(), 12),
+            Err(DecodingError::OutputLimitExceeded {
+                limit: 12,
+                required: 13
+            })
+        );
+        assert_eq!(
+            read_framed_with_limit(&mut "WWWWWWWL".as_bytes(), 1 << 20),
+            Err(DecodingError::OutputLimitExceeded {
+                limit: 1 << 20,
+                required: 437_400_000_000
+            })
+        );
+    }
+}
@@ -0,0 +1,364 @@
+use std::borrow::{Borrow, Cow};
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Deref, Range};
+
+use crate::decoding::{compute_chunk, compute_decoded_size, DecodeIter};
+use crate::errors::{DecodingError, VerificationError};
+use crate::{decode, decode_in_slice, verify, G60String};
+
+/// A borrowed G60 encoded string that is guaranteed to be valid and canonical.
+///
+/// It is to [G60String] what `str` is to `String`, so APIs can accept `&G60Str` without
+/// forcing an allocation. Like [G60String], its order is the byte-wise order of the
+/// decoded contents.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct G60Str(str);
+
+impl G60Str {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Verifies `encoded` and borrows it as a [G60Str].
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical G60 encoded string.
+    pub fn new(encoded: &str) -> Result<&G60Str, VerificationError> {
+        verify(encoded)?;
+
+        // SAFETY: `encoded` has just been verified.
+        Ok(unsafe { Self::new_unchecked(encoded) })
+    }
+
+    /// Borrows `encoded` as a [G60Str] without verifying it.
+    ///
+    /// # Safety
+    /// `encoded` must be a valid canonical G60 encoded string.
+    pub unsafe fn new_unchecked(encoded: &str) -> &G60Str {
+        // SAFETY: `G60Str` is a transparent wrapper over `str`.
+        unsafe { &*(encoded as *const str as *const G60Str) }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the encoded string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the length of the encoded string.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the encoded string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the length of the decoded content.
+    pub fn decoded_len(&self) -> usize {
+        compute_decoded_size(self.0.len())
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Decodes the encoded string.
+    pub fn decode(&self) -> Vec<u8> {
+        decode(&self.0).unwrap()
+    }
+
+    /// Returns an iterator over the decoded bytes that decodes each group on demand,
+    /// without allocating the decoded content.
+    pub fn decoded_bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        DecodeIter::new(self.0.as_bytes()).map(Result::unwrap)
+    }
+
+    /// Returns an iterator over the 11 char groups of the encoded string, including the
+    /// trailing incomplete group, each paired with its decoded bytes.
+    ///
+    /// The decoded bytes of the incomplete group are padded with zeros, so only the first
+    /// `8 * group.len() / 11` belong to the content.
+    ///
+    /// ```rust
+    /// # use g60::G60String;
+    /// let encoded = G60String::encode(b"Hello, world!");
+    /// let groups: Vec<_> = encoded.groups().collect();
+    ///
+    /// assert_eq!(groups[0], ("Gt4CGFiHehz", *b"Hello, w"));
+    /// assert_eq!(groups[1], ("RzjCF16", *b"orld!\0\0\0"));
+    /// ```
+    pub fn groups(&self) -> impl Iterator<Item = (&str, [u8; 8])> + '_ {
+        self.0.as_bytes().chunks(11).map(|chunk| {
+            // SAFETY: the encoded string contains only ASCII characters.
+            let group = unsafe { std::str::from_utf8_unchecked(chunk) };
+
+            (group, compute_chunk(0, chunk).unwrap())
+        })
+    }
+
+    /// Returns the encoding of the decoded bytes in `range`, decoding only the groups that
+    /// overlap it. If the range starts at a group boundary, the complete groups are copied
+    /// without decoding them.
+    ///
+    /// ```rust
+    /// # use g60::G60String;
+    /// let encoded = G60String::encode(b"Hello, world!");
+    ///
+    /// assert_eq!(encoded.slice_decoded(7..12), G60String::encode(b"world"));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `range` is not within the decoded content.
+    pub fn slice_decoded(&self, range: Range<usize>) -> G60String {
+        assert!(
+            range.start <= range.end && range.end <= self.decoded_len(),
+            "The range {:?} is out of the decoded content of length {}",
+            range,
+            self.decoded_len()
+        );
+
+        let chars_start = range.start / 8 * 11;
+        let chars_end = (range.end.div_ceil(8) * 11).min(self.0.len());
+        let offset = range.start % 8;
+        let length = range.end - range.start;
+
+        if offset == 0 {
+            // SAFETY: a sequence of groups is a valid canonical G60 encoded string.
+            let mut result =
+                unsafe { G60String::new_unchecked(self.0[chars_start..chars_end].to_owned()) };
+            result.truncate_decoded(length);
+
+            return result;
+        }
+
+        let decoded = decode(&self.0[chars_start..chars_end]).unwrap();
+        G60String::encode(&decoded[offset..offset + length])
+    }
+
+    /// Splits the encoded string into the encodings of the decoded bytes before and after
+    /// `index`.
+    ///
+    /// If `index` is a multiple of 8, both halves are copied without decoding anything.
+    /// Otherwise, the last group of the left half is re-encoded and, since every following
+    /// byte shifts within its group, the whole right half is decoded and re-encoded.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the decoded length.
+    pub fn split_at_decoded(&self, index: usize) -> (G60String, G60String) {
+        (
+            self.slice_decoded(0..index),
+            self.slice_decoded(index..self.decoded_len()),
+        )
+    }
+
+    /// Decodes the encoded string.
+    /// The result is placed into `slice` and returns the number of elements written.
+    ///
+    /// # Errors
+    /// An error will be thrown if `slice` does not have enough space to store the decoded string.
+    pub fn decode_in_slice(&self, slice: &mut [u8]) -> Result<usize, DecodingError> {
+        decode_in_slice(&self.0, slice)
+    }
+}
+
+impl Debug for G60Str {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for G60Str {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Deref for G60Str {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Borrow<str> for G60Str {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for G60Str {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<G60Str> for G60Str {
+    fn as_ref(&self) -> &G60Str {
+        self
+    }
+}
+
+impl ToOwned for G60Str {
+    type Owned = G60String;
+
+    fn to_owned(&self) -> Self::Owned {
+        // SAFETY: a `G60Str` is always a valid canonical G60 encoded string.
+        unsafe { G60String::new_unchecked(self.0.to_owned()) }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a G60Str {
+    type Error = VerificationError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        G60Str::new(value)
+    }
+}
+
+/// A G60 encoded string that is either borrowed, e.g. from an already verified header, or
+/// owned. It dereferences to [G60Str], so it has the same decoding API.
+///
+/// ```rust
+/// # use g60::{G60CowString, G60Str, G60String};
+/// let borrowed: G60CowString = G60Str::new("Gt4CGFiHehzRzjCF16").unwrap().into();
+/// let owned: G60CowString = G60String::encode(b"Hello, world!").into();
+///
+/// assert_eq!(borrowed, owned);
+/// assert_eq!(borrowed.decode(), b"Hello, world!");
+/// ```
+pub type G60CowString<'a> = Cow<'a, G60Str>;
+
+impl<'a> From<&'a G60Str> for G60CowString<'a> {
+    fn from(encoded: &'a G60Str) -> Self {
+        Cow::Borrowed(encoded)
+    }
+}
+
+impl<'a> From<&'a G60String> for G60CowString<'a> {
+    fn from(encoded: &'a G60String) -> Self {
+        Cow::Borrowed(encoded.as_g60_str())
+    }
+}
+
+impl From<G60String> for G60CowString<'_> {
+    fn from(encoded: G60String) -> Self {
+        Cow::Owned(encoded)
+    }
+}
+
+impl From<G60CowString<'_>> for G60String {
+    fn from(encoded: G60CowString<'_>) -> Self {
+        encoded.into_owned()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let encoded = G60Str::new("Gt4CGFiHehzRzjCF16").expect("The verification must succeed");
+
+        assert_eq!(encoded.as_str(), "Gt4CGFiHehzRzjCF16");
+        assert_eq!(encoded.len(), 18);
+        assert_eq!(encoded.decoded_len(), 13);
+        assert_eq!(encoded.decode(), b"Hello, world!");
+
+        assert_eq!(
+            G60Str::new("Hello, world!"),
+            Err(VerificationError::InvalidByte {
+                index: 5,
+                byte: b',',
+            })
+        );
+        assert_eq!(G60Str::new("0f"), Err(VerificationError::NotCanonical));
+    }
+
+    #[test]
+    fn test_decode_in_slice() {
+        let encoded = G60Str::new("Gt4CGFiHehzRzjCF16").unwrap();
+        let mut slice = [0; 13];
+
+        assert_eq!(encoded.decode_in_slice(&mut slice), Ok(13));
+        assert_eq!(&slice, b"Hello, world!");
+    }
+
+    #[test]
+    fn test_slice_decoded() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(30).collect();
+        let encoded = G60String::encode(&bytes);
+
+        for start in 0..=bytes.len() {
+            for end in start..=bytes.len() {
+                assert_eq!(
+                    encoded.slice_decoded(start..end),
+                    G60String::encode(&bytes[start..end]),
+                    "Incorrect for {}..{}",
+                    start,
+                    end
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_decoded_out_of_bounds() {
+        G60Str::new("Gt4CGFiHehzRzjCF16")
+            .unwrap()
+            .slice_decoded(10..14);
+    }
+
+    #[test]
+    fn test_split_at_decoded() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(30).collect();
+        let encoded = G60String::encode(&bytes);
+
+        for index in 0..=bytes.len() {
+            let (left, right) = encoded.split_at_decoded(index);
+
+            assert_eq!(
+                left,
+                G60String::encode(&bytes[..index]),
+                "Incorrect for {}",
+                index
+            );
+            assert_eq!(
+                right,
+                G60String::encode(&bytes[index..]),
+                "Incorrect for {}",
+                index
+            );
+            assert_eq!(G60String::concat(&[left, right]), encoded);
+        }
+    }
+
+    #[test]
+    fn test_cow() {
+        let text = "Gt4CGFiHehzRzjCF16";
+        let borrowed = G60CowString::from(G60Str::new(text).unwrap());
+
+        assert!(matches!(borrowed, Cow::Borrowed(_)));
+        assert_eq!(borrowed.decode(), b"Hello, world!");
+
+        let mut owned = borrowed.clone();
+        owned.to_mut().push_bytes(b"!");
+        assert!(matches!(owned, Cow::Owned(_)));
+        assert_eq!(owned.decode(), b"Hello, world!!");
+        assert_eq!(G60String::from(borrowed), text);
+    }
+
+    #[test]
+    fn test_to_owned() {
+        let encoded = G60Str::new("Gt4CGFiHehzRzjCF16").unwrap();
+        let owned: G60String = encoded.to_owned();
+
+        assert_eq!(&*owned, encoded);
+    }
+}
@@ -0,0 +1,772 @@
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::encoding::compute_encoded_size;
+use crate::errors::VerificationError;
+use crate::{
+    decoding, encode, encode_in_slice, encode_into, encoding, verify, AnyValidG60String, G60Str,
+    G60StringBuilder,
+};
+
+/// An owned G60 encoded string that is guaranteed to be valid and canonical.
+///
+/// The encoding is monotonic, so the lexicographic order of canonical encoded strings is
+/// the same as the byte-wise order of their decoded contents. This makes them suitable
+/// as sorted keys, for example in a database.
+///
+/// It dereferences to [G60Str], which dereferences to `str`, so it can be used wherever a
+/// `&str` is expected.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct G60String(String);
+
+impl G60String {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Verifies `encoded` and wraps it into a [G60String].
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical G60 encoded string.
+    pub fn new(encoded: String) -> Result<G60String, VerificationError> {
+        verify(&encoded)?;
+
+        Ok(G60String(encoded))
+    }
+
+    /// Verifies `encoded` and wraps it into a [G60String] along with its decoded content,
+    /// computed in the same pass over each group instead of verifying it and decoding it
+    /// again.
+    ///
+    /// ```rust
+    /// # use g60::G60String;
+    /// let (encoded, decoded) = G60String::new_and_decode("Gt4CGFiHehzRzjCF16".to_string()).unwrap();
+    ///
+    /// assert_eq!(encoded.as_str(), "Gt4CGFiHehzRzjCF16");
+    /// assert_eq!(decoded, b"Hello, world!");
+    /// ```
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical G60 encoded string.
+    pub fn new_and_decode(encoded: String) -> Result<(G60String, Vec<u8>), VerificationError> {
+        let decoded = decoding::decode_verified(&encoded)?;
+
+        Ok((G60String(encoded), decoded))
+    }
+
+    /// Wraps `encoded` into a [G60String] without verifying it.
+    ///
+    /// # Safety
+    /// `encoded` must be a valid canonical G60 encoded string.
+    pub unsafe fn new_unchecked(encoded: String) -> G60String {
+        G60String(encoded)
+    }
+
+    /// Repairs `encoded` into a [G60String]: an invalid trailing length is truncated to the
+    /// nearest shorter valid length and the last incomplete group is canonicalized.
+    /// Returns the result and a report of what was changed.
+    ///
+    /// ```rust
+    /// # use g60::{G60String, RepairReport};
+    /// let (encoded, report) = G60String::canonicalize_and_repair("Gt4CGFiHehzRzjCF17z").unwrap();
+    ///
+    /// assert_eq!(encoded.as_str(), "Gt4CGFiHehzRzjCF16");
+    /// assert_eq!(report, RepairReport { truncated_chars: 1, canonicalized: true });
+    /// ```
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` has any invalid char or a group whose fields
+    /// do not fit in a byte, which cannot be repaired.
+    pub fn canonicalize_and_repair(
+        encoded: &str,
+    ) -> Result<(G60String, RepairReport), VerificationError> {
+        let truncated_chars = match encoded.len() % 11 {
+            1 | 4 | 8 => 1,
+            _ => 0,
+        };
+        let length = encoded.len() - truncated_chars;
+
+        if !encoded.is_char_boundary(length) {
+            let index = encoded.bytes().position(|v| !v.is_ascii()).unwrap();
+            return Err(VerificationError::InvalidByte {
+                index,
+                byte: encoded.as_bytes()[index],
+            });
+        }
+
+        let encoded = AnyValidG60String::new(encoded[..length].to_string())?;
+        let canonicalized = !encoded.is_canonical();
+        let encoded = encoded.canonicalize();
+
+        Ok((
+            encoded,
+            RepairReport {
+                truncated_chars,
+                canonicalized,
+            },
+        ))
+    }
+
+    /// Encodes `content` into a [G60String].
+    pub fn encode(content: &[u8]) -> G60String {
+        G60String(encode(content))
+    }
+
+    /// Builds the encoding of the concatenation of the contents encoded in `parts`,
+    /// re-encoding only the groups around the boundaries that are not group aligned.
+    ///
+    /// Concatenating the encoded strings directly is not a valid encoding unless every
+    /// part but the last has a decoded length multiple of 8.
+    ///
+    /// ```rust
+    /// # use g60::G60String;
+    /// let parts = [G60String::encode(b"Hello, "), G60String::encode(b"world!")];
+    ///
+    /// assert_eq!(G60String::concat(&parts), G60String::encode(b"Hello, world!"));
+    /// ```
+    pub fn concat<S: AsRef<G60Str>>(parts: &[S]) -> G60String {
+        let mut builder = G60StringBuilder::new();
+        for part in parts {
+            builder.push_encoded(part.as_ref());
+        }
+
+        builder.finish()
+    }
+
+    /// Like [concat](G60String::concat) but inserting the bytes of `separator` between
+    /// the contents of every two parts.
+    ///
+    /// ```rust
+    /// # use g60::G60String;
+    /// let parts = [G60String::encode(b"Hello"), G60String::encode(b"world!")];
+    ///
+    /// assert_eq!(G60String::join(&parts, b", "), G60String::encode(b"Hello, world!"));
+    /// ```
+    pub fn join<S: AsRef<G60Str>>(parts: &[S], separator: &[u8]) -> G60String {
+        let mut builder = G60StringBuilder::new();
+        for (i, part) in parts.iter().enumerate() {
+            if i != 0 {
+                builder.push_bytes(separator);
+            }
+
+            builder.push_encoded(part.as_ref());
+        }
+
+        builder.finish()
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Borrows the encoded string as a [G60Str].
+    pub fn as_g60_str(&self) -> &G60Str {
+        // SAFETY: the inner string of a `G60String` is always a valid canonical G60 encoded
+        // string.
+        unsafe { G60Str::new_unchecked(&self.0) }
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Returns the inner encoded string.
+    pub fn into_string(mut self) -> String {
+        std::mem::take(&mut self.0)
+    }
+
+    /// Appends the encoding of `content` to the encoded string, decoding and re-encoding
+    /// only the trailing incomplete group, so the result is the encoding of the previous
+    /// content followed by `content`.
+    pub fn push_bytes(&mut self, content: &[u8]) {
+        let tail_start = self.0.len() / 11 * 11;
+        if tail_start == self.0.len() {
+            encode_into(content, &mut self.0);
+            return;
+        }
+
+        let (mut group, group_length) =
+            decoding::decode_last_group(0, &self.0.as_bytes()[tail_start..]).unwrap();
+        let (head, rest) = content.split_at((8 - group_length).min(content.len()));
+        let new_group_length = group_length + head.len();
+        group[group_length..new_group_length].copy_from_slice(head);
+
+        let encoded = encoding::compute_chunk(&group[..new_group_length]);
+        self.0.truncate(tail_start);
+        // SAFETY: the encoded string contains only ASCII characters.
+        self.0.push_str(unsafe {
+            std::str::from_utf8_unchecked(&encoded[..compute_encoded_size(new_group_length)])
+        });
+
+        encode_into(rest, &mut self.0);
+    }
+
+    /// Decodes the content into a scratch buffer, lets `f` modify it and re-encodes the
+    /// result into the same allocation, e.g. to mask or patch fields of an encoded blob.
+    ///
+    /// ```rust
+    /// # use g60::G60String;
+    /// let mut encoded = G60String::encode(b"Hello, world!");
+    /// encoded.map_decoded(|bytes| bytes[7..12].fill(b'*'));
+    ///
+    /// assert_eq!(encoded, G60String::encode(b"Hello, *****!"));
+    /// ```
+    pub fn map_decoded(&mut self, f: impl FnOnce(&mut [u8])) {
+        let mut decoded = self.decode();
+        f(&mut decoded);
+
+        // SAFETY: the encoded string contains only ASCII characters and the decoded
+        // length does not change, so neither does the encoded one.
+        encode_in_slice(&decoded, unsafe { self.0.as_mut_vec() }).unwrap();
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut decoded);
+    }
+
+    /// Truncates the encoded string to the encoding of its first `length` decoded bytes,
+    /// re-encoding only the last group. It has no effect if `length` is greater than or
+    /// equal to the decoded length.
+    pub fn truncate_decoded(&mut self, length: usize) {
+        if length >= self.decoded_len() {
+            return;
+        }
+
+        let group_start = length / 8 * 11;
+        let tail_length = length % 8;
+
+        if tail_length != 0 {
+            let decoded = decoding::compute_chunk(0, &self.0.as_bytes()[group_start..]).unwrap();
+            let encoded = encoding::compute_chunk(&decoded[..tail_length]);
+
+            self.0.truncate(group_start);
+            // SAFETY: the encoded string contains only ASCII characters.
+            self.0.push_str(unsafe {
+                std::str::from_utf8_unchecked(&encoded[..compute_encoded_size(tail_length)])
+            });
+        } else {
+            self.0.truncate(group_start);
+        }
+    }
+}
+
+impl Debug for G60String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for G60String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Deref for G60String {
+    type Target = G60Str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_g60_str()
+    }
+}
+
+impl Borrow<G60Str> for G60String {
+    fn borrow(&self) -> &G60Str {
+        self.as_g60_str()
+    }
+}
+
+impl Borrow<str> for G60String {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<G60Str> for G60String {
+    fn as_ref(&self) -> &G60Str {
+        self.as_g60_str()
+    }
+}
+
+impl AsRef<str> for G60String {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&[u8]> for G60String {
+    fn from(content: &[u8]) -> Self {
+        G60String::encode(content)
+    }
+}
+
+impl From<&Vec<u8>> for G60String {
+    fn from(content: &Vec<u8>) -> Self {
+        G60String::encode(content)
+    }
+}
+
+impl From<Vec<u8>> for G60String {
+    fn from(content: Vec<u8>) -> Self {
+        G60String::encode(&content)
+    }
+}
+
+impl From<&G60Str> for G60String {
+    fn from(encoded: &G60Str) -> Self {
+        encoded.to_owned()
+    }
+}
+
+impl From<G60String> for String {
+    fn from(encoded: G60String) -> Self {
+        encoded.into_string()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for G60String {
+    /// Zeroes the encoded string, leaving it empty, which is still valid.
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for G60String {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for G60String {}
+
+impl TryFrom<String> for G60String {
+    type Error = VerificationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        G60String::new(value)
+    }
+}
+
+impl TryFrom<&str> for G60String {
+    type Error = VerificationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(G60Str::new(value)?.to_owned())
+    }
+}
+
+impl FromStr for G60String {
+    type Err = VerificationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        G60String::try_from(s)
+    }
+}
+
+/// The changes made by [G60String::canonicalize_and_repair].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct RepairReport {
+    /// The number of chars removed from the end to get a valid length.
+    pub truncated_chars: usize,
+    /// Whether the last incomplete group was not canonical.
+    pub canonicalized: bool,
+}
+
+/// Implements the symmetric equality between `$a` and `$b` comparing them as `str`.
+macro_rules! impl_str_eq {
+    ($a:ty, $b:ty) => {
+        impl PartialEq<$b> for $a {
+            fn eq(&self, other: &$b) -> bool {
+                AsRef::<str>::as_ref(self) == AsRef::<str>::as_ref(other)
+            }
+        }
+
+        impl PartialEq<$a> for $b {
+            fn eq(&self, other: &$a) -> bool {
+                AsRef::<str>::as_ref(self) == AsRef::<str>::as_ref(other)
+            }
+        }
+    };
+}
+
+impl_str_eq!(G60String, str);
+impl_str_eq!(G60String, &str);
+impl_str_eq!(G60String, String);
+impl_str_eq!(G60String, G60Str);
+impl_str_eq!(G60String, &G60Str);
+impl_str_eq!(G60Str, str);
+impl_str_eq!(G60Str, &str);
+impl_str_eq!(G60Str, String);
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_new() {
+        let encoded = G60String::new("Gt4CGFiHehzRzjCF16".to_string())
+            .expect("The verification must succeed");
+
+        assert_eq!(encoded.as_str(), "Gt4CGFiHehzRzjCF16");
+        assert_eq!(encoded.decode(), b"Hello, world!");
+        assert_eq!(
+            G60String::new("0f".to_string()),
+            Err(VerificationError::NotCanonical)
+        );
+    }
+
+    #[test]
+    fn test_new_and_decode() {
+        let long_length = 8 * crate::constants::BLOCK_GROUPS * 2 + 13;
+        for length in [0, 1, 7, 8, 13, 100, long_length] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = G60String::encode(&bytes);
+
+            assert_eq!(
+                G60String::new_and_decode(encoded.to_string()),
+                Ok((encoded.clone(), bytes)),
+                "Incorrect for '{}'",
+                encoded.as_str()
+            );
+        }
+
+        for test in [
+            "0f",
+            "Gt4CGFiHehzRzjCF1O",
+            "Gt4CGFiHehzRzjCF16z",
+            "zzzzzzzzzzz",
+        ] {
+            assert_eq!(
+                G60String::new_and_decode(test.to_string()).map(|(v, _)| v),
+                G60String::new(test.to_string()),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+
+        // Invalid byte after the first block of groups.
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(long_length).collect();
+        let mut test = G60String::encode(&bytes).into_string();
+        test.replace_range(
+            11 * crate::constants::BLOCK_GROUPS + 3..11 * crate::constants::BLOCK_GROUPS + 4,
+            "O",
+        );
+        assert_eq!(
+            G60String::new_and_decode(test.clone()).map(|(v, _)| v),
+            G60String::new(test),
+        );
+    }
+
+    #[test]
+    fn test_decoded_bytes() {
+        for length in [0, 1, 7, 8, 13, 100] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = G60String::encode(&bytes);
+
+            assert!(
+                encoded.decoded_bytes().eq(bytes.iter().copied()),
+                "Incorrect for '{}'",
+                encoded.as_str()
+            );
+        }
+
+        let encoded = G60String::encode(b"Hello, world!");
+        assert_eq!(
+            encoded.decoded_bytes().position(|v| v == b','),
+            Some(5),
+            "Incorrect for '{}'",
+            encoded.as_str()
+        );
+    }
+
+    #[test]
+    fn test_groups() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(100).collect();
+        let encoded = G60String::encode(&bytes);
+        let groups: Vec<_> = encoded.groups().collect();
+
+        assert_eq!(groups.len(), 13, "Incorrect group count");
+        for (i, (group, decoded)) in groups.iter().enumerate() {
+            let start = i * 8;
+            let end = (start + 8).min(bytes.len());
+
+            assert_eq!(
+                *group,
+                &encoded.as_str()[i * 11..(i * 11 + 11).min(encoded.len())]
+            );
+            assert_eq!(
+                decoded[..end - start],
+                bytes[start..end],
+                "Incorrect for '{}'",
+                group
+            );
+            assert!(
+                decoded[end - start..].iter().all(|v| *v == 0),
+                "Incorrect for '{}'",
+                group
+            );
+        }
+
+        assert_eq!(G60String::encode(b"").groups().count(), 0);
+    }
+
+    #[test]
+    fn test_concat() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(40).collect();
+
+        for part_length in 1..=20 {
+            let parts: Vec<G60String> = bytes.chunks(part_length).map(G60String::encode).collect();
+
+            assert_eq!(
+                G60String::concat(&parts),
+                G60String::encode(&bytes),
+                "Incorrect for parts of {}",
+                part_length
+            );
+
+            let expected: Vec<u8> = bytes
+                .chunks(part_length)
+                .collect::<Vec<_>>()
+                .join(&b"--"[..]);
+            assert_eq!(
+                G60String::join(&parts, b"--"),
+                G60String::encode(&expected),
+                "Incorrect for parts of {}",
+                part_length
+            );
+        }
+
+        assert_eq!(G60String::concat::<G60String>(&[]).as_str(), "");
+    }
+
+    #[test]
+    fn test_map_decoded() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(30).collect();
+
+        for index in 0..bytes.len() {
+            let mut encoded = G60String::encode(&bytes);
+            let capacity = encoded.0.capacity();
+            encoded.map_decoded(|v| v[index] = !v[index]);
+
+            let mut expected = bytes.clone();
+            expected[index] = !expected[index];
+
+            assert_eq!(
+                encoded,
+                G60String::encode(&expected),
+                "Incorrect for {}",
+                index
+            );
+            assert_eq!(encoded.0.capacity(), capacity, "Incorrect for {}", index);
+        }
+    }
+
+    #[test]
+    fn test_truncate_decoded() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(30).collect();
+
+        for length in 0..=35 {
+            let mut encoded = G60String::encode(&bytes);
+            encoded.truncate_decoded(length);
+
+            assert_eq!(
+                encoded,
+                G60String::encode(&bytes[..length.min(bytes.len())]),
+                "Incorrect for length {}",
+                length
+            );
+        }
+    }
+
+    #[test]
+    fn test_push_bytes() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(30).collect();
+
+        for split in 0..=bytes.len() {
+            for end in [split, split + 1, split + 9, bytes.len()] {
+                let end = end.min(bytes.len());
+                let mut encoded = G60String::encode(&bytes[..split]);
+                encoded.push_bytes(&bytes[split..end]);
+
+                assert_eq!(
+                    encoded,
+                    G60String::encode(&bytes[..end]),
+                    "Incorrect for {}..{}",
+                    split,
+                    end
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_equality() {
+        let encoded = G60String::encode(b"Hello, world!");
+        let text = "Gt4CGFiHehzRzjCF16";
+
+        assert_eq!(encoded, *text);
+        assert_eq!(encoded, text);
+        assert_eq!(encoded, text.to_string());
+        assert_eq!(*text, encoded);
+        assert_eq!(text, encoded);
+        assert_eq!(text.to_string(), encoded);
+        assert_eq!(encoded, *encoded.as_g60_str());
+        assert_eq!(encoded, encoded.as_g60_str());
+        assert_eq!(*encoded.as_g60_str(), encoded);
+        assert_eq!(*encoded.as_g60_str(), *text);
+        assert_eq!(text, *encoded.as_g60_str());
+        assert_ne!(encoded, "Gt4CGFiHehzRzjCF06");
+    }
+
+    #[test]
+    fn test_formatting() {
+        let encoded = G60String::encode(b"Hello, world!");
+
+        assert_eq!(format!("{}", encoded), "Gt4CGFiHehzRzjCF16");
+        assert_eq!(format!("{:>20}", encoded), "  Gt4CGFiHehzRzjCF16");
+        assert_eq!(format!("{:?}", encoded), "\"Gt4CGFiHehzRzjCF16\"");
+        assert_eq!(format!("{}", encoded.as_g60_str()), "Gt4CGFiHehzRzjCF16");
+        assert_eq!(
+            format!("{:?}", encoded.as_g60_str()),
+            "\"Gt4CGFiHehzRzjCF16\""
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..10000 {
+            let length_a = rng.gen_range(0..20);
+            let length_b = rng.gen_range(0..20);
+            let mut a: Vec<u8> = (0..length_a).map(|_| rng.gen()).collect();
+            let b: Vec<u8> = (0..length_b).map(|_| rng.gen()).collect();
+
+            // Shared prefixes.
+            if rng.gen() {
+                let prefix_length = rng.gen_range(0..=length_a.min(length_b));
+                a[..prefix_length].copy_from_slice(&b[..prefix_length]);
+            }
+
+            assert_eq!(
+                G60String::encode(&a).cmp(&G60String::encode(&b)),
+                a.cmp(&b),
+                "Incorrect for {:?} and {:?}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut set = HashSet::new();
+        set.insert(G60String::encode(b"Hello, world!"));
+
+        assert!(set.contains(G60Str::new("Gt4CGFiHehzRzjCF16").unwrap()));
+        assert!(!set.contains(G60Str::new("Gt4CGFiHehzRzjCF06").unwrap()));
+    }
+
+    #[test]
+    fn test_str_interoperability() {
+        fn starts_with_g(text: &str) -> bool {
+            text.starts_with('G')
+        }
+
+        let encoded = G60String::encode(b"Hello, world!");
+        assert!(starts_with_g(&encoded));
+        assert_eq!(encoded.find('z'), Some(10));
+        assert_eq!(encoded.to_lowercase(), "gt4cgfihehzrzjcf16");
+
+        let mut map = HashMap::new();
+        map.insert(encoded.clone(), 1);
+        assert_eq!(map.get("Gt4CGFiHehzRzjCF16"), Some(&1));
+        assert_eq!(map.get(encoded.as_g60_str()), Some(&1));
+        assert_eq!(map.get("Gt4CGFiHehzRzjCF06"), None);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut encoded = G60String::encode(b"Hello, world!");
+        encoded.zeroize();
+
+        assert_eq!(encoded.as_str(), "");
+        assert_eq!(encoded.decode(), b"");
+    }
+
+    #[test]
+    fn test_canonicalize_and_repair() {
+        for (test, expected, truncated_chars, canonicalized) in [
+            ("", "", 0, false),
+            ("Gt4CGFiHehzRzjCF16", "Gt4CGFiHehzRzjCF16", 0, false),
+            ("Gt4CGFiHehzRzjCF17", "Gt4CGFiHehzRzjCF16", 0, true),
+            ("Gt4CGFiHehzRzjCF16z", "Gt4CGFiHehzRzjCF16", 1, false),
+            ("Gt4CGFiHehzRzjCF17z", "Gt4CGFiHehzRzjCF16", 1, true),
+            ("0", "", 1, false),
+        ] {
+            assert_eq!(
+                G60String::canonicalize_and_repair(test),
+                Ok((
+                    G60String::new(expected.to_string()).unwrap(),
+                    RepairReport {
+                        truncated_chars,
+                        canonicalized,
+                    }
+                )),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+
+        for (test, index, byte) in [
+            ("Gt4CGFiHehzRzjCF1O", 17, b'O'),
+            ("Gt4CGFiHe\u{e9}", 9, 0xC3),
+            ("Gt4CGF\u{e9}", 6, 0xC3),
+        ] {
+            assert_eq!(
+                G60String::canonicalize_and_repair(test),
+                Err(VerificationError::InvalidByte { index, byte }),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let encoded = G60String::from(b"Hello, world!".as_slice());
+        assert_eq!(G60String::from(b"Hello, world!".to_vec()), encoded);
+        assert_eq!(G60String::from(&b"Hello, world!".to_vec()), encoded);
+
+        assert_eq!(encoded.as_str(), "Gt4CGFiHehzRzjCF16");
+        assert_eq!(
+            "Gt4CGFiHehzRzjCF16".parse::<G60String>().as_ref(),
+            Ok(&encoded)
+        );
+        assert_eq!(
+            G60String::try_from("Gt4CGFiHehzRzjCF16"),
+            Ok(encoded.clone())
+        );
+        assert_eq!(String::from(encoded), "Gt4CGFiHehzRzjCF16");
+        assert!(G60String::try_from("Hello, world!").is_err());
+    }
+
+    #[test]
+    fn test_borrow() {
+        fn decoded_len(encoded: &G60Str) -> usize {
+            encoded.decoded_len()
+        }
+
+        let encoded = G60String::encode(b"Hello, world!");
+
+        assert_eq!(decoded_len(&encoded), 13);
+        assert_eq!(Borrow::<G60Str>::borrow(&encoded), encoded.as_g60_str());
+    }
+}
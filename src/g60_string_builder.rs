@@ -0,0 +1,168 @@
+use crate::decoding::decode_last_group;
+use crate::encoding::{compute_chunk, compute_encoded_size};
+use crate::{decode, encode_into, G60Str, G60String};
+
+/// A builder that assembles a [G60String] from several parts, keeping the trailing
+/// incomplete group decoded so every part is encoded only once.
+///
+/// ```rust
+/// # use g60::{G60String, G60StringBuilder};
+/// let separator = G60String::encode(b", ");
+///
+/// let mut builder = G60StringBuilder::new();
+/// builder
+///     .push_bytes(b"Hello")
+///     .push_encoded(&separator)
+///     .push_bytes(b"world!");
+///
+/// assert_eq!(builder.finish(), G60String::encode(b"Hello, world!"));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct G60StringBuilder {
+    encoded: String,
+    group: [u8; 8],
+    group_length: usize,
+}
+
+impl G60StringBuilder {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds an empty builder.
+    pub fn new() -> G60StringBuilder {
+        Self::default()
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the length of the content pushed so far.
+    pub fn decoded_len(&self) -> usize {
+        self.encoded.len() / 11 * 8 + self.group_length
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Appends `content`.
+    pub fn push_bytes(&mut self, content: &[u8]) -> &mut Self {
+        // Fill the incomplete group.
+        let (head, rest) = content.split_at((8 - self.group_length).min(content.len()));
+        self.group[self.group_length..self.group_length + head.len()].copy_from_slice(head);
+        self.group_length += head.len();
+
+        if self.group_length < 8 {
+            return self;
+        }
+
+        self.push_group();
+
+        // Complete groups.
+        let complete_groups_length = rest.len() >> 3 << 3;
+        encode_into(&rest[..complete_groups_length], &mut self.encoded);
+
+        // Last incomplete group.
+        let tail = &rest[complete_groups_length..];
+        self.group[..tail.len()].copy_from_slice(tail);
+        self.group_length = tail.len();
+
+        self
+    }
+
+    /// Appends the content encoded in `encoded`. If the content pushed so far fills
+    /// complete groups, the complete groups of `encoded` are copied without decoding them.
+    pub fn push_encoded(&mut self, encoded: &G60Str) -> &mut Self {
+        if self.group_length != 0 {
+            return self.push_bytes(&decode(encoded.as_str()).unwrap());
+        }
+
+        let bytes = encoded.as_str().as_bytes();
+        let complete_groups_length = bytes.len() / 11 * 11;
+        self.encoded
+            .push_str(&encoded.as_str()[..complete_groups_length]);
+
+        let (group, group_length) = decode_last_group(0, &bytes[complete_groups_length..]).unwrap();
+        self.group = group;
+        self.group_length = group_length;
+
+        self
+    }
+
+    /// Encodes the trailing incomplete group and returns the built [G60String].
+    pub fn finish(mut self) -> G60String {
+        self.push_group();
+
+        // SAFETY: the builder only appends complete groups and a final incomplete one.
+        unsafe { G60String::new_unchecked(self.encoded) }
+    }
+
+    // AUX METHODS ------------------------------------------------------------
+
+    /// Encodes the bytes of the current group and clears it.
+    fn push_group(&mut self) {
+        if self.group_length == 0 {
+            return;
+        }
+
+        let encoded = compute_chunk(&self.group[..self.group_length]);
+        // SAFETY: the encoded string contains only ASCII characters.
+        self.encoded.push_str(unsafe {
+            std::str::from_utf8_unchecked(&encoded[..compute_encoded_size(self.group_length)])
+        });
+        self.group_length = 0;
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_bytes() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(50).collect();
+
+        for part_length in 1..=20 {
+            let mut builder = G60StringBuilder::new();
+            for part in bytes.chunks(part_length) {
+                builder.push_bytes(part);
+            }
+
+            assert_eq!(builder.decoded_len(), bytes.len());
+            assert_eq!(
+                builder.finish(),
+                G60String::encode(&bytes),
+                "Incorrect for parts of {}",
+                part_length
+            );
+        }
+    }
+
+    #[test]
+    fn test_push_encoded() {
+        let bytes: Vec<u8> = (0..=255u8).cycle().take(50).collect();
+
+        for split in 0..=bytes.len() {
+            for header_length in [0, 3, 8] {
+                let header_length = header_length.min(split);
+                let encoded = G60String::encode(&bytes[header_length..split]);
+
+                let mut builder = G60StringBuilder::new();
+                builder
+                    .push_bytes(&bytes[..header_length])
+                    .push_encoded(&encoded)
+                    .push_bytes(&bytes[split..]);
+
+                assert_eq!(
+                    builder.finish(),
+                    G60String::encode(&bytes),
+                    "Incorrect for {}..{}",
+                    header_length,
+                    split
+                );
+            }
+        }
+
+        assert_eq!(G60StringBuilder::new().finish().as_str(), "");
+    }
+}
@@ -0,0 +1,248 @@
+//! A geohash-like encoding of geographic coordinates in the G60 alphabet.
+//!
+//! Each char splits the cell of the previous chars into a grid of 60 smaller cells, 6
+//! latitude rows by 10 longitude columns for the chars at even positions and 10 by 6 for
+//! the ones at odd positions, interleaving the latitude and longitude digits like geohash
+//! interleaves their bits. As a result, every pair of chars refines both coordinates 60
+//! times, and every prefix of an encoded string is the cell that contains it, so nearby
+//! points usually share a prefix.
+//!
+//! ```rust
+//! let encoded = g60::geo::encode(40.4168, -3.7038, 6);
+//! let cell = g60::geo::decode(&encoded).unwrap();
+//!
+//! assert_eq!(encoded.len(), 6);
+//! assert!(cell.contains(40.4168, -3.7038));
+//! assert!(g60::geo::decode(&encoded[..3]).unwrap().contains(40.4168, -3.7038));
+//! ```
+
+use crate::constants::{ENCODED_TO_UTF8_MAP, UTF8_TO_ENCODED_MAP};
+use crate::errors::VerificationError;
+
+/// A cell of the encoding, bounded by the minimum and maximum latitudes and longitudes in
+/// degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_latitude: f64,
+    pub max_latitude: f64,
+    pub min_longitude: f64,
+    pub max_longitude: f64,
+}
+
+impl BoundingBox {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the latitude and longitude of the center of the box.
+    pub fn center(&self) -> (f64, f64) {
+        (
+            (self.min_latitude + self.max_latitude) / 2.0,
+            (self.min_longitude + self.max_longitude) / 2.0,
+        )
+    }
+
+    /// Returns whether the box contains the point, including its minimum edges but not its
+    /// maximum ones.
+    pub fn contains(&self, latitude: f64, longitude: f64) -> bool {
+        (self.min_latitude..self.max_latitude).contains(&latitude)
+            && (self.min_longitude..self.max_longitude).contains(&longitude)
+    }
+}
+
+/// The maximum precision, beyond which the cells are smaller than the precision of `f64`.
+pub const MAX_PRECISION: usize = 16;
+
+/// Encodes a point as a string of `precision` chars. The maximum latitude and longitude
+/// are included in the last cells.
+///
+/// # Panics
+/// Panics if `latitude` is not in `-90.0..=90.0`, `longitude` is not in `-180.0..=180.0`
+/// or `precision` is greater than [MAX_PRECISION].
+pub fn encode(latitude: f64, longitude: f64, precision: usize) -> String {
+    assert!(
+        (-90.0..=90.0).contains(&latitude),
+        "The latitude must be in -90.0..=90.0"
+    );
+    assert!(
+        (-180.0..=180.0).contains(&longitude),
+        "The longitude must be in -180.0..=180.0"
+    );
+    assert!(
+        precision <= MAX_PRECISION,
+        "The precision must not be greater than {}",
+        MAX_PRECISION
+    );
+
+    // The cells are computed at the maximum precision, so the encoding of a point with any
+    // precision is a prefix of the ones with greater precisions.
+    let (max_latitude_cells, max_longitude_cells) = cell_counts(MAX_PRECISION);
+    let (mut latitude_cells, mut longitude_cells) = cell_counts(precision);
+    let mut latitude_index = cell_index((latitude + 90.0) / 180.0, max_latitude_cells)
+        / (max_latitude_cells / latitude_cells);
+    let mut longitude_index = cell_index((longitude + 180.0) / 360.0, max_longitude_cells)
+        / (max_longitude_cells / longitude_cells);
+    let mut result = String::with_capacity(precision);
+
+    for index in 0..precision {
+        let (latitude_base, longitude_base) = bases(index);
+        latitude_cells /= latitude_base;
+        longitude_cells /= longitude_base;
+
+        let value =
+            latitude_index / latitude_cells * longitude_base + longitude_index / longitude_cells;
+        latitude_index %= latitude_cells;
+        longitude_index %= longitude_cells;
+
+        result.push(ENCODED_TO_UTF8_MAP[value as usize] as char);
+    }
+
+    result
+}
+
+/// Decodes the cell of an encoded point.
+///
+/// # Errors
+/// An error will be thrown if `encoded` contains any char outside the G60 alphabet or it
+/// is longer than [MAX_PRECISION].
+pub fn decode(encoded: &str) -> Result<BoundingBox, VerificationError> {
+    if encoded.len() > MAX_PRECISION {
+        return Err(VerificationError::InvalidLength);
+    }
+
+    let mut latitude_index = 0;
+    let mut longitude_index = 0;
+
+    for (index, byte) in encoded.bytes().enumerate() {
+        let value = *UTF8_TO_ENCODED_MAP.get(byte as usize).unwrap_or(&255);
+        if value == 255 {
+            return Err(VerificationError::InvalidByte { index, byte });
+        }
+
+        let (latitude_base, longitude_base) = bases(index);
+        latitude_index = latitude_index * latitude_base + value as u64 / longitude_base;
+        longitude_index = longitude_index * longitude_base + value as u64 % longitude_base;
+    }
+
+    let (latitude_cells, longitude_cells) = cell_counts(encoded.len());
+    let latitude_size = 180.0 / latitude_cells as f64;
+    let longitude_size = 360.0 / longitude_cells as f64;
+
+    Ok(BoundingBox {
+        min_latitude: -90.0 + latitude_size * latitude_index as f64,
+        max_latitude: -90.0 + latitude_size * (latitude_index + 1) as f64,
+        min_longitude: -180.0 + longitude_size * longitude_index as f64,
+        max_longitude: -180.0 + longitude_size * (longitude_index + 1) as f64,
+    })
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Returns the number of latitude rows and longitude columns of the char at `index`.
+fn bases(index: usize) -> (u64, u64) {
+    if index % 2 == 0 {
+        (6, 10)
+    } else {
+        (10, 6)
+    }
+}
+
+/// Returns the number of latitude rows and longitude columns of the cells of `precision`
+/// chars.
+fn cell_counts(precision: usize) -> (u64, u64) {
+    (0..precision)
+        .map(bases)
+        .fold((1, 1), |(a, b), (c, d)| (a * c, b * d))
+}
+
+/// Returns the index of the cell that contains the normalized coordinate `value`, in
+/// `0.0..=1.0`, among `cells` cells.
+fn cell_index(value: f64, cells: u64) -> u64 {
+    ((value * cells as f64) as u64).min(cells - 1)
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        for (latitude, longitude, expected) in [
+            (-90.0, -180.0, "0000"),
+            (90.0, 180.0, "zzzz"),
+            (0.0, 0.0, "b000"),
+        ] {
+            assert_eq!(
+                encode(latitude, longitude, 4),
+                expected,
+                "Incorrect for ({}, {})",
+                latitude,
+                longitude
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let points = [
+            (40.4168, -3.7038),
+            (-33.8688, 151.2093),
+            (64.1466, -21.9426),
+            (0.0, 0.0),
+            (89.999, 179.999),
+        ];
+
+        for (latitude, longitude) in points {
+            let mut previous = String::new();
+
+            for precision in 0..=MAX_PRECISION {
+                let encoded = encode(latitude, longitude, precision);
+                let cell = decode(&encoded).expect("The decoding must succeed");
+
+                assert_eq!(encoded.len(), precision);
+                // Points on the edges of the cells may fall on either side due to rounding.
+                assert!(
+                    cell.min_latitude - 1e-12 <= latitude
+                        && latitude <= cell.max_latitude + 1e-12
+                        && cell.min_longitude - 1e-12 <= longitude
+                        && longitude <= cell.max_longitude + 1e-12,
+                    "Incorrect for ({}, {}) at {}",
+                    latitude,
+                    longitude,
+                    precision
+                );
+                assert!(
+                    encoded.starts_with(&previous),
+                    "Incorrect for '{}'",
+                    encoded
+                );
+
+                previous = encoded;
+            }
+        }
+
+        // Two chars refine both coordinates 60 times.
+        let cell = decode(&encode(40.4168, -3.7038, 2)).unwrap();
+        assert!((cell.max_latitude - cell.min_latitude - 3.0).abs() < 1e-9);
+        assert!((cell.max_longitude - cell.min_longitude - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode(&"0".repeat(MAX_PRECISION + 1)),
+            Err(VerificationError::InvalidLength)
+        );
+        assert_eq!(
+            decode("U0O"),
+            Err(VerificationError::InvalidByte {
+                index: 2,
+                byte: b'O',
+            })
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::errors::{DecodingError, VerificationError};
+use crate::{decode, encode};
+
+/// Encodes a list of bytes into a G60 encoding format split into groups of `group_length`
+/// chars joined by `separator`, e.g. `Gt4C-GFiH-ehzR-zjCF-16`, to display codes to humans.
+///
+/// # Panics
+/// Panics if `group_length` is 0.
+pub fn encode_grouped(content: &[u8], group_length: usize, separator: &str) -> String {
+    assert_ne!(group_length, 0, "The group length cannot be 0");
+
+    let encoded = encode(content);
+    let groups = encoded.len().div_ceil(group_length);
+    let mut result =
+        String::with_capacity(encoded.len() + groups.saturating_sub(1) * separator.len());
+
+    for (i, group) in encoded.as_bytes().chunks(group_length).enumerate() {
+        if i != 0 {
+            result.push_str(separator);
+        }
+
+        // SAFETY: the encoded string contains only ASCII characters.
+        result.push_str(unsafe { std::str::from_utf8_unchecked(group) });
+    }
+
+    result
+}
+
+/// Decodes a G60 encoded string formatted by [encode_grouped] with the same `group_length`
+/// and `separator`.
+///
+/// # Errors
+/// An error will be thrown if `encoded` is not a valid canonical G60 encoded string or its
+/// groups are not exactly `group_length` chars long, except the last one that can be shorter.
+/// The indexes of the errors are relative to `encoded`, including the separators.
+///
+/// # Panics
+/// Panics if `group_length` is 0 or `separator` is empty.
+pub fn decode_grouped(
+    encoded: &str,
+    group_length: usize,
+    separator: &str,
+) -> Result<Vec<u8>, DecodingError> {
+    assert_ne!(group_length, 0, "The group length cannot be 0");
+    assert!(!separator.is_empty(), "The separator cannot be empty");
+
+    let bytes = encoded.as_bytes();
+    let mut result = String::with_capacity(encoded.len());
+    let mut offset = 0;
+    let mut groups = encoded.split(separator).peekable();
+
+    while let Some(group) = groups.next() {
+        let is_last = groups.peek().is_none();
+
+        let invalid_index = if group.len() > group_length {
+            // Missing separator.
+            Some(offset + group_length)
+        } else if !is_last && group.len() < group_length {
+            // Early separator.
+            Some(offset + group.len())
+        } else if is_last && group.is_empty() && offset != 0 {
+            // Trailing separator.
+            Some(offset - separator.len())
+        } else {
+            None
+        };
+
+        if let Some(index) = invalid_index {
+            return Err(DecodingError::Verification(
+                VerificationError::InvalidByte {
+                    index,
+                    byte: bytes[index],
+                },
+            ));
+        }
+
+        result.push_str(group);
+        offset += group.len() + separator.len();
+    }
+
+    decode(&result).map_err(|error| match error {
+        DecodingError::Verification(VerificationError::InvalidByte { index, byte }) => {
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: index + index / group_length * separator.len(),
+                byte,
+            })
+        }
+        error => error,
+    })
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_grouped() {
+        let test = b"Hello, world!";
+
+        assert_eq!(encode_grouped(test, 4, "-"), "Gt4C-GFiH-ehzR-zjCF-16");
+        assert_eq!(encode_grouped(test, 6, " - "), "Gt4CGF - iHehzR - zjCF16");
+        assert_eq!(encode_grouped(test, 100, "-"), "Gt4CGFiHehzRzjCF16");
+        assert_eq!(encode_grouped(b"", 4, "-"), "");
+    }
+
+    #[test]
+    fn test_decode_grouped() {
+        for length in [0, 1, 7, 8, 13, 100] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+
+            for (group_length, separator) in [(1, "-"), (4, "-"), (6, " - "), (100, "_")] {
+                let encoded = encode_grouped(&bytes, group_length, separator);
+                let decoded = decode_grouped(&encoded, group_length, separator)
+                    .expect("The decoding must succeed");
+
+                assert_eq!(decoded, bytes, "Incorrect for '{}'", encoded);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_grouped_errors() {
+        for (test, index, byte) in [
+            ("Gt4C-GFiHe-hzR-zjCF-16", 9, b'e'),
+            ("Gt4C-GFi-HehzR-zjCF-16", 8, b'-'),
+            ("Gt4C-GFiH-ehzR-zjCF-", 19, b'-'),
+            ("Gt4C-GFiH-ehzR-zjCF-1O", 21, b'O'),
+            ("-Gt4C", 0, b'-'),
+        ] {
+            let error = decode_grouped(test, 4, "-").expect_err("The decoding cannot succeed");
+
+            assert_eq!(
+                error,
+                DecodingError::Verification(VerificationError::InvalidByte { index, byte }),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Lexicographically sortable identifiers, like ULIDs, rendered in G60.
+//!
+//! A [G60Ulid] is a 128-bit value made of a 48-bit millisecond Unix timestamp followed by
+//! 80 random bits. Its G60 encoding has 22 chars and sorts in the same order as the
+//! identifiers, i.e. by creation time.
+//!
+//! ```rust
+//! # use g60::id::G60Ulid;
+//! let id = G60Ulid::from_parts(1_700_000_000_000, 42);
+//! let encoded = id.to_string();
+//!
+//! assert_eq!(encoded.len(), 22);
+//! assert_eq!(encoded.parse::<G60Ulid>().unwrap(), id);
+//! assert_eq!(id.timestamp(), 1_700_000_000_000);
+//! ```
+
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+#[cfg(feature = "getrandom")]
+use std::sync::Mutex;
+#[cfg(feature = "getrandom")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::errors::DecodingError;
+use crate::{decode_u128, encode_u128, G60String};
+
+/// The number of random bits of an identifier.
+const RANDOM_BITS: u32 = 80;
+
+/// The mask of the random bits of an identifier.
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+
+/// The mask of the timestamp bits of an identifier.
+const TIMESTAMP_MASK: u64 = (1 << (128 - RANDOM_BITS)) - 1;
+
+/// The last identifier generated by [G60Ulid::new] in this process.
+#[cfg(feature = "getrandom")]
+static LAST_ID: Mutex<u128> = Mutex::new(0);
+
+/// A lexicographically sortable identifier made of a millisecond timestamp and random bits.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct G60Ulid(u128);
+
+impl G60Ulid {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Generates a new identifier with the current time and random bits from the operating
+    /// system's CSPRNG.
+    ///
+    /// The identifiers generated in this process are strictly increasing: when the clock
+    /// has not advanced past the last identifier, its random bits are incremented instead.
+    ///
+    /// # Panics
+    /// Panics if the operating system's CSPRNG fails.
+    #[cfg(feature = "getrandom")]
+    pub fn new() -> G60Ulid {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|v| v.as_millis() as u64)
+            .unwrap_or(0);
+
+        let mut random = [0; 16];
+        getrandom::getrandom(&mut random).expect("The random generation must succeed");
+
+        let id = G60Ulid::from_parts(timestamp, u128::from_be_bytes(random));
+        let mut last_id = LAST_ID.lock().unwrap_or_else(|e| e.into_inner());
+        *last_id = next_id(*last_id, id.0);
+
+        G60Ulid(*last_id)
+    }
+
+    /// Builds an identifier from a millisecond Unix timestamp and the random bits. Only the
+    /// lower 48 bits of `timestamp` and the lower 80 bits of `random` are used.
+    pub fn from_parts(timestamp: u64, random: u128) -> G60Ulid {
+        G60Ulid((((timestamp & TIMESTAMP_MASK) as u128) << RANDOM_BITS) | (random & RANDOM_MASK))
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the millisecond Unix timestamp of the identifier.
+    pub fn timestamp(&self) -> u64 {
+        (self.0 >> RANDOM_BITS) as u64
+    }
+
+    /// Returns the random bits of the identifier.
+    pub fn random(&self) -> u128 {
+        self.0 & RANDOM_MASK
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Encodes the identifier as a 22-char G60 string.
+    pub fn to_g60_string(&self) -> G60String {
+        // SAFETY: the encoding of an integer is always valid and canonical.
+        unsafe { G60String::new_unchecked(encode_u128(self.0)) }
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl Default for G60Ulid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for G60Ulid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&encode_u128(self.0), f)
+    }
+}
+
+impl Display for G60Ulid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&encode_u128(self.0), f)
+    }
+}
+
+impl FromStr for G60Ulid {
+    type Err = DecodingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(G60Ulid(decode_u128(s)?))
+    }
+}
+
+impl From<u128> for G60Ulid {
+    fn from(value: u128) -> Self {
+        G60Ulid(value)
+    }
+}
+
+impl From<G60Ulid> for u128 {
+    fn from(id: G60Ulid) -> Self {
+        id.0
+    }
+}
+
+impl From<G60Ulid> for G60String {
+    fn from(id: G60Ulid) -> Self {
+        id.to_g60_string()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Returns the identifier that follows `last`: `generated` if it is greater, or `last`
+/// incremented by one, which carries into the timestamp if the random bits overflow.
+#[cfg(feature = "getrandom")]
+fn next_id(last: u128, generated: u128) -> u128 {
+    if generated > last {
+        generated
+    } else {
+        last.wrapping_add(1)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_parts() {
+        let id = G60Ulid::from_parts(u64::MAX, u128::MAX);
+
+        assert_eq!(id.timestamp(), TIMESTAMP_MASK);
+        assert_eq!(id.random(), RANDOM_MASK);
+        assert_eq!(u128::from(id), u128::MAX);
+
+        let id = G60Ulid::from_parts(1_700_000_000_000, 42);
+
+        assert_eq!(id.timestamp(), 1_700_000_000_000);
+        assert_eq!(id.random(), 42);
+        assert!(id < G60Ulid::from_parts(1_700_000_000_001, 0));
+        assert!(id.to_string() < G60Ulid::from_parts(1_700_000_000_001, 0).to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        let id = G60Ulid::from_parts(1_700_000_000_000, 42);
+
+        assert_eq!(id.to_g60_string().as_str(), id.to_string());
+        assert_eq!(id.to_string().parse::<G60Ulid>(), Ok(id));
+        assert_eq!(
+            "Gt4CGFiHehzRzjCF16".parse::<G60Ulid>(),
+            Err(DecodingError::UnexpectedDecodedLength {
+                expected: 16,
+                actual: 13,
+            })
+        );
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_new() {
+        let mut previous = G60Ulid::new();
+
+        for _ in 0..10000 {
+            let id = G60Ulid::new();
+
+            assert!(id > previous, "Incorrect for {}", id);
+            assert!(
+                id.to_string() > previous.to_string(),
+                "Incorrect for {}",
+                id
+            );
+            assert!(
+                id.timestamp() >= previous.timestamp(),
+                "Incorrect for {}",
+                id
+            );
+
+            previous = id;
+        }
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_next_id() {
+        let last = G60Ulid::from_parts(10, 5).0;
+
+        assert_eq!(
+            next_id(last, G60Ulid::from_parts(11, 0).0),
+            G60Ulid::from_parts(11, 0).0
+        );
+        assert_eq!(
+            next_id(last, G60Ulid::from_parts(10, 3).0),
+            G60Ulid::from_parts(10, 6).0
+        );
+        assert_eq!(
+            next_id(last, G60Ulid::from_parts(9, 9).0),
+            G60Ulid::from_parts(10, 6).0
+        );
+        assert_eq!(
+            next_id(
+                G60Ulid::from_parts(10, RANDOM_MASK).0,
+                G60Ulid::from_parts(10, 0).0
+            ),
+            G60Ulid::from_parts(11, 0).0
+        );
+    }
+}
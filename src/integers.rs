@@ -0,0 +1,340 @@
+use crate::errors::DecodingError;
+#[cfg(feature = "num-bigint")]
+use crate::errors::VerificationError;
+use crate::{decode_exact, encode};
+
+/// Implements the order-preserving encoding and decoding functions of an unsigned integer
+/// type, encoding it as its big-endian bytes.
+macro_rules! impl_unsigned {
+    ($ty:ty, $encode:ident, $decode:ident, $length:literal) => {
+        #[doc = concat!("Encodes a `", stringify!($ty), "` as a fixed-length G60 string of ")]
+        #[doc = concat!($length, " chars whose lexicographic order is the same as the order")]
+        /// of the integers, so it can be used in sortable keys.
+        pub fn $encode(value: $ty) -> String {
+            encode(&value.to_be_bytes())
+        }
+
+        #[doc = concat!("Decodes a `", stringify!($ty), "` encoded with [", stringify!($encode), "].")]
+        ///
+        /// # Errors
+        #[doc = concat!("An error will be thrown if `encoded` does not have ", $length, " chars or")]
+        /// it is not a valid canonical G60 encoded string.
+        pub fn $decode(encoded: &str) -> Result<$ty, DecodingError> {
+            Ok(<$ty>::from_be_bytes(decode_exact(encoded)?))
+        }
+    };
+}
+
+/// Implements the order-preserving encoding and decoding functions of a signed integer
+/// type, encoding it as the big-endian bytes of its value with the sign bit flipped,
+/// i.e. offset by `2^(bits - 1)`, so the negative values sort before the positive ones.
+macro_rules! impl_signed {
+    ($ty:ty, $encode:ident, $decode:ident, $length:literal) => {
+        #[doc = concat!("Encodes an `", stringify!($ty), "` as a fixed-length G60 string of ")]
+        #[doc = concat!($length, " chars whose lexicographic order is the same as the order")]
+        /// of the integers, including the negative ones.
+        pub fn $encode(value: $ty) -> String {
+            encode(&(value ^ <$ty>::MIN).to_be_bytes())
+        }
+
+        #[doc = concat!("Decodes an `", stringify!($ty), "` encoded with [", stringify!($encode), "].")]
+        ///
+        /// # Errors
+        #[doc = concat!("An error will be thrown if `encoded` does not have ", $length, " chars or")]
+        /// it is not a valid canonical G60 encoded string.
+        pub fn $decode(encoded: &str) -> Result<$ty, DecodingError> {
+            Ok(<$ty>::from_be_bytes(decode_exact(encoded)?) ^ <$ty>::MIN)
+        }
+    };
+}
+
+impl_unsigned!(u16, encode_u16, decode_u16, 3);
+impl_unsigned!(u32, encode_u32, decode_u32, 6);
+impl_unsigned!(u64, encode_u64, decode_u64, 11);
+impl_unsigned!(u128, encode_u128, decode_u128, 22);
+impl_signed!(i32, encode_i32, decode_i32, 6);
+impl_signed!(i64, encode_i64, decode_i64, 11);
+
+/// Encodes an `f64` as a fixed-length G60 string of 11 chars whose lexicographic order is
+/// the same as the numeric order of the floats, so it can be used in range-scannable keys.
+///
+/// The sign bit of the positive floats is flipped and all the bits of the negative ones
+/// are inverted, so the order is the one of [f64::total_cmp]: `-0.0` sorts right before
+/// `0.0`, and the NaNs sort beyond the infinite of their sign.
+///
+/// ```rust
+/// let keys: Vec<_> = [-1.5, 0.0, 2.25].map(g60::encode_f64).into();
+///
+/// assert!(keys.is_sorted());
+/// assert_eq!(g60::decode_f64(&keys[0]).unwrap(), -1.5);
+/// ```
+pub fn encode_f64(value: f64) -> String {
+    let bits = value.to_bits();
+
+    if bits >> 63 == 0 {
+        encode_u64(bits ^ (1 << 63))
+    } else {
+        encode_u64(!bits)
+    }
+}
+
+/// Decodes an `f64` encoded with [encode_f64].
+///
+/// # Errors
+/// An error will be thrown if `encoded` does not have 11 chars or it is not a valid
+/// canonical G60 encoded string.
+pub fn decode_f64(encoded: &str) -> Result<f64, DecodingError> {
+    let bits = decode_u64(encoded)?;
+
+    if bits >> 63 == 1 {
+        Ok(f64::from_bits(bits ^ (1 << 63)))
+    } else {
+        Ok(f64::from_bits(!bits))
+    }
+}
+
+/// Encodes an arbitrary-precision unsigned integer as the G60 encoding of its minimal
+/// big-endian bytes, i.e. without leading zeros, so zero is encoded as an empty string.
+///
+/// Unlike the fixed-width integers, the output length depends on the value so the order
+/// is not preserved.
+///
+/// ```rust
+/// # use num_bigint::BigUint;
+/// let value = BigUint::from(1u8) << 100;
+/// let encoded = g60::encode_biguint(&value);
+///
+/// assert_eq!(g60::decode_biguint(&encoded).unwrap(), value);
+/// ```
+#[cfg(feature = "num-bigint")]
+pub fn encode_biguint(value: &num_bigint::BigUint) -> String {
+    if value.bits() == 0 {
+        return String::new();
+    }
+
+    encode(&value.to_bytes_be())
+}
+
+/// Decodes an arbitrary-precision unsigned integer encoded with [encode_biguint].
+///
+/// # Errors
+/// An error will be thrown if `encoded` is not a valid canonical G60 encoded string or
+/// its decoded content has leading zeros, because then it is not the minimal encoding.
+#[cfg(feature = "num-bigint")]
+pub fn decode_biguint(encoded: &str) -> Result<num_bigint::BigUint, DecodingError> {
+    let bytes = crate::decode(encoded)?;
+
+    if bytes.first() == Some(&0) {
+        return Err(VerificationError::NotCanonical.into());
+    }
+
+    Ok(num_bigint::BigUint::from_bytes_be(&bytes))
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng};
+
+    use crate::errors::VerificationError;
+
+    use super::*;
+
+    #[test]
+    fn test_u16() {
+        let mut previous = encode_u16(0);
+
+        for value in 0..=u16::MAX {
+            let encoded = encode_u16(value);
+
+            assert_eq!(encoded.len(), 3, "Incorrect for {}", value);
+            assert!(encoded >= previous, "Incorrect for {}", value);
+            assert_eq!(decode_u16(&encoded), Ok(value), "Incorrect for {}", value);
+
+            previous = encoded;
+        }
+    }
+
+    #[test]
+    fn test_u32_and_u64() {
+        let mut random = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..10000 {
+            let (a, b): (u32, u32) = (random.gen(), random.gen());
+            let (encoded_a, encoded_b) = (encode_u32(a), encode_u32(b));
+
+            assert_eq!(encoded_a.len(), 6, "Incorrect for {}", a);
+            assert_eq!(encoded_a.cmp(&encoded_b), a.cmp(&b), "Incorrect for {}", a);
+            assert_eq!(decode_u32(&encoded_a), Ok(a), "Incorrect for {}", a);
+
+            let (a, b): (u64, u64) = (random.gen(), random.gen());
+            let (encoded_a, encoded_b) = (encode_u64(a), encode_u64(b));
+
+            assert_eq!(encoded_a.len(), 11, "Incorrect for {}", a);
+            assert_eq!(encoded_a.cmp(&encoded_b), a.cmp(&b), "Incorrect for {}", a);
+            assert_eq!(decode_u64(&encoded_a), Ok(a), "Incorrect for {}", a);
+        }
+
+        for value in [0, 1, u64::MAX - 1, u64::MAX] {
+            assert_eq!(
+                decode_u64(&encode_u64(value)),
+                Ok(value),
+                "Incorrect for {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_u128() {
+        let mut random = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..10000 {
+            let (a, b): (u128, u128) = (random.gen(), random.gen());
+            let (encoded_a, encoded_b) = (encode_u128(a), encode_u128(b));
+
+            assert_eq!(encoded_a.len(), 22, "Incorrect for {}", a);
+            assert_eq!(encoded_a.cmp(&encoded_b), a.cmp(&b), "Incorrect for {}", a);
+            assert_eq!(decode_u128(&encoded_a), Ok(a), "Incorrect for {}", a);
+        }
+
+        // Boundaries of the 64-bit halves.
+        let high = (u64::MAX as u128) << 64;
+        for value in [0, 1, u64::MAX as u128, high, high + 1, u128::MAX] {
+            assert_eq!(
+                decode_u128(&encode_u128(value)),
+                Ok(value),
+                "Incorrect for {}",
+                value
+            );
+            assert!(encode_u128(value) <= encode_u128(value.saturating_add(1)));
+        }
+    }
+
+    #[test]
+    fn test_signed() {
+        let mut random = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..10000 {
+            let (a, b): (i32, i32) = (random.gen(), random.gen());
+            let (encoded_a, encoded_b) = (encode_i32(a), encode_i32(b));
+
+            assert_eq!(encoded_a.len(), 6, "Incorrect for {}", a);
+            assert_eq!(encoded_a.cmp(&encoded_b), a.cmp(&b), "Incorrect for {}", a);
+            assert_eq!(decode_i32(&encoded_a), Ok(a), "Incorrect for {}", a);
+
+            let (a, b): (i64, i64) = (random.gen(), random.gen());
+            let (encoded_a, encoded_b) = (encode_i64(a), encode_i64(b));
+
+            assert_eq!(encoded_a.len(), 11, "Incorrect for {}", a);
+            assert_eq!(encoded_a.cmp(&encoded_b), a.cmp(&b), "Incorrect for {}", a);
+            assert_eq!(decode_i64(&encoded_a), Ok(a), "Incorrect for {}", a);
+        }
+
+        let values = [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX];
+        for pair in values.windows(2) {
+            assert!(
+                encode_i64(pair[0]) < encode_i64(pair[1]),
+                "Incorrect for {}",
+                pair[0]
+            );
+            assert_eq!(decode_i64(&encode_i64(pair[0])), Ok(pair[0]));
+        }
+    }
+
+    #[test]
+    fn test_f64() {
+        let values = [
+            f64::NEG_INFINITY,
+            f64::MIN,
+            -1.5,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE,
+            1.0,
+            2.25,
+            f64::MAX,
+            f64::INFINITY,
+            f64::NAN,
+        ];
+
+        for pair in values.windows(2) {
+            let encoded = encode_f64(pair[0]);
+
+            assert_eq!(encoded.len(), 11, "Incorrect for {}", pair[0]);
+            assert!(encoded < encode_f64(pair[1]), "Incorrect for {}", pair[0]);
+            assert_eq!(
+                decode_f64(&encoded).map(f64::to_bits),
+                Ok(pair[0].to_bits()),
+                "Incorrect for {}",
+                pair[0]
+            );
+        }
+
+        let mut random = rand::rngs::StdRng::seed_from_u64(0);
+        for _ in 0..10000 {
+            let (a, b) = (f64::from_bits(random.gen()), f64::from_bits(random.gen()));
+
+            assert_eq!(
+                encode_f64(a).cmp(&encode_f64(b)),
+                a.total_cmp(&b),
+                "Incorrect for {}",
+                a
+            );
+        }
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn test_biguint() {
+        use num_bigint::BigUint;
+
+        assert_eq!(encode_biguint(&BigUint::from(0u8)), "");
+        assert_eq!(encode_biguint(&BigUint::from(2u8)), "0U");
+        assert_eq!(decode_biguint(""), Ok(BigUint::from(0u8)));
+
+        let mut random = rand::rngs::StdRng::seed_from_u64(0);
+        for length in 1..100 {
+            let bytes: Vec<u8> = (0..length).map(|_| random.gen()).collect();
+            let value = BigUint::from_bytes_be(&bytes);
+            let encoded = encode_biguint(&value);
+
+            assert_eq!(
+                encoded,
+                encode(&value.to_bytes_be()),
+                "Incorrect for {}",
+                value
+            );
+            assert_eq!(
+                decode_biguint(&encoded),
+                Ok(value),
+                "Incorrect for {}",
+                encoded
+            );
+        }
+
+        assert_eq!(
+            decode_biguint("000"),
+            Err(DecodingError::Verification(VerificationError::NotCanonical))
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode_u32("0000000"),
+            Err(DecodingError::UnexpectedDecodedLength {
+                expected: 4,
+                actual: 5,
+            })
+        );
+        assert_eq!(
+            decode_u16("zzz"),
+            Err(DecodingError::Verification(VerificationError::NotCanonical))
+        );
+    }
+}
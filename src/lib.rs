@@ -15,17 +15,159 @@
 //! # }
 //! ```
 
+pub use alphabet::Alphabet;
+pub use any_valid_g60_string::AnyValidG60String;
+pub use arc_g60_string::ArcG60String;
+pub use arc_g60_string::G60Interner;
+pub use bits::decode_bits;
+pub use bits::encode_bits;
+pub use cached_g60_string::CachedG60String;
+pub use canonicalization::canonicalize_reader_to_writer;
+pub use checksum::decode_check;
+pub use checksum::decode_with_check_char;
+pub use checksum::encode_check;
+pub use checksum::encode_with_check_char;
+#[cfg(feature = "subtle")]
+pub use constant_time::constant_time_eq;
+pub use decode_config::CanonicalityPolicy;
+pub use decode_config::DecodeConfig;
+pub use decode_config::LengthPolicy;
 pub use decoding::decode;
+pub use decoding::decode_exact;
 pub use decoding::decode_in_slice;
+pub use decoding::decode_in_slice_ref;
 pub use decoding::decode_in_writer;
+pub use decoding::decode_in_writer_with_progress;
+pub use decoding::decode_into;
+pub use decoding::decode_iter;
+pub use decoding::decode_lossy;
+pub use decoding::decode_partial;
+pub use decoding::decode_with_canonicality;
+pub use decoding::decode_with_limit;
+#[cfg(feature = "digest")]
+pub use digest_support::encode_digest;
+#[cfg(feature = "digest")]
+pub use digest_support::G60Digest;
+pub use encode_config::EncodeConfig;
+pub use encode_config::LineEnding;
 pub use encoding::encode;
 pub use encoding::encode_in_slice;
+pub use encoding::encode_in_slice_str;
 pub use encoding::encode_in_writer;
+pub use encoding::encode_into;
+pub use encoding::encode_iter;
+pub use facade::Encoding;
+pub use facade::G60;
+pub use framing::read_framed;
+pub use framing::read_framed_with_limit;
+pub use framing::write_framed;
+pub use g60_str::G60CowString;
+pub use g60_str::G60Str;
+pub use g60_string::G60String;
+pub use g60_string::RepairReport;
+pub use g60_string_builder::G60StringBuilder;
+pub use grouping::decode_grouped;
+pub use grouping::encode_grouped;
+#[cfg(feature = "num-bigint")]
+pub use integers::decode_biguint;
+pub use integers::decode_f64;
+pub use integers::decode_i32;
+pub use integers::decode_i64;
+pub use integers::decode_u128;
+pub use integers::decode_u16;
+pub use integers::decode_u32;
+pub use integers::decode_u64;
+#[cfg(feature = "num-bigint")]
+pub use integers::encode_biguint;
+pub use integers::encode_f64;
+pub use integers::encode_i32;
+pub use integers::encode_i64;
+pub use integers::encode_u128;
+pub use integers::encode_u16;
+pub use integers::encode_u32;
+pub use integers::encode_u64;
+pub use net::decode_ipv4;
+pub use net::decode_ipv6;
+pub use net::encode_ipv4;
+pub use net::encode_ipv6;
+#[cfg(feature = "parallel")]
+pub use parallel::decode_parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::encode_parallel;
+#[cfg(feature = "threads")]
+pub use pipeline::decode_reader_to_writer;
+#[cfg(feature = "threads")]
+pub use pipeline::encode_reader_to_writer;
+#[cfg(feature = "secrecy")]
+pub use secret::SecretG60String;
+pub use streaming::DecoderWriter;
+pub use streaming::EncoderWriter;
+pub use time::decode_system_time;
+pub use time::encode_system_time;
+#[cfg(feature = "uuid")]
+pub use uuid_support::decode_uuid;
+#[cfg(feature = "uuid")]
+pub use uuid_support::encode_uuid;
 pub use verification::verify;
+pub use verification::verify_file;
 
+mod alphabet;
+mod any_valid_g60_string;
+mod arc_g60_string;
+pub mod base60;
+mod bits;
+#[cfg(feature = "bson")]
+mod bson_support;
+mod cached_g60_string;
+mod canonicalization;
+mod checksum;
+#[cfg(feature = "subtle")]
+mod constant_time;
 mod constants;
+mod decode_config;
 mod decoding;
+#[cfg(feature = "digest")]
+mod digest_support;
+mod dispatch;
+#[cfg(feature = "ecc")]
+pub mod ecc;
+mod encode_config;
 mod encoding;
 pub mod errors;
+mod facade;
+mod framing;
+mod g60_str;
+mod g60_string;
+mod g60_string_builder;
+pub mod geo;
+mod grouping;
+pub mod id;
+mod integers;
+mod net;
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "threads")]
+mod pipeline;
+#[cfg(feature = "postgres-types")]
+mod postgres_support;
+#[cfg(feature = "redis")]
+mod redis_support;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_support;
+#[cfg(feature = "secrecy")]
+mod secret;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "sqlx")]
+mod sqlx_support;
+mod streaming;
+mod time;
+pub mod token;
 mod utils;
+#[cfg(feature = "utoipa")]
+mod utoipa_support;
+#[cfg(feature = "uuid")]
+mod uuid_support;
 mod verification;
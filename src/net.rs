@@ -0,0 +1,103 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::errors::DecodingError;
+use crate::{decode_u128, decode_u32, encode_u128, encode_u32};
+
+/// Encodes an IPv4 address as a fixed-length G60 string of 6 chars that sorts in the same
+/// order as the addresses.
+///
+/// ```rust
+/// # use std::net::Ipv4Addr;
+/// let encoded = g60::encode_ipv4(Ipv4Addr::new(192, 168, 1, 1));
+///
+/// assert_eq!(encoded.len(), 6);
+/// assert_eq!(g60::decode_ipv4(&encoded).unwrap(), Ipv4Addr::new(192, 168, 1, 1));
+/// ```
+pub fn encode_ipv4(address: Ipv4Addr) -> String {
+    encode_u32(address.to_bits())
+}
+
+/// Decodes an IPv4 address encoded with [encode_ipv4].
+///
+/// # Errors
+/// An error will be thrown if `encoded` does not have 6 chars or it is not a valid
+/// canonical G60 encoded string.
+pub fn decode_ipv4(encoded: &str) -> Result<Ipv4Addr, DecodingError> {
+    Ok(Ipv4Addr::from_bits(decode_u32(encoded)?))
+}
+
+/// Encodes an IPv6 address as a fixed-length G60 string of 22 chars that sorts in the
+/// same order as the addresses.
+pub fn encode_ipv6(address: Ipv6Addr) -> String {
+    encode_u128(address.to_bits())
+}
+
+/// Decodes an IPv6 address encoded with [encode_ipv6].
+///
+/// # Errors
+/// An error will be thrown if `encoded` does not have 22 chars or it is not a valid
+/// canonical G60 encoded string.
+pub fn decode_ipv6(encoded: &str) -> Result<Ipv6Addr, DecodingError> {
+    Ok(Ipv6Addr::from_bits(decode_u128(encoded)?))
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ipv4() {
+        for address in [
+            Ipv4Addr::UNSPECIFIED,
+            Ipv4Addr::LOCALHOST,
+            Ipv4Addr::new(192, 168, 1, 1),
+            Ipv4Addr::BROADCAST,
+        ] {
+            let encoded = encode_ipv4(address);
+
+            assert_eq!(encoded.len(), 6, "Incorrect for {}", address);
+            assert_eq!(
+                decode_ipv4(&encoded),
+                Ok(address),
+                "Incorrect for {}",
+                address
+            );
+        }
+
+        assert!(
+            encode_ipv4(Ipv4Addr::new(10, 0, 0, 255)) < encode_ipv4(Ipv4Addr::new(10, 0, 1, 0))
+        );
+    }
+
+    #[test]
+    fn test_ipv6() {
+        for address in [
+            Ipv6Addr::UNSPECIFIED,
+            Ipv6Addr::LOCALHOST,
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+            Ipv4Addr::new(192, 168, 1, 1).to_ipv6_mapped(),
+        ] {
+            let encoded = encode_ipv6(address);
+
+            assert_eq!(encoded.len(), 22, "Incorrect for {}", address);
+            assert_eq!(
+                decode_ipv6(&encoded),
+                Ok(address),
+                "Incorrect for {}",
+                address
+            );
+        }
+
+        assert_eq!(
+            decode_ipv6(&encode_ipv4(Ipv4Addr::LOCALHOST)),
+            Err(DecodingError::UnexpectedDecodedLength {
+                expected: 16,
+                actual: 4,
+            })
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use rayon::prelude::*;
+
+use crate::decoding::compute_decoded_size;
+use crate::dispatch;
+use crate::encoding::compute_encoded_size;
+use crate::errors::{DecodingError, VerificationError};
+use crate::{decoding, encoding};
+
+/// Number of complete groups processed by each parallel task.
+const PARALLEL_BLOCK_GROUPS: usize = 8 * 1024;
+
+/// Encodes a list of bytes into a G60 encoding format splitting the work across the
+/// rayon thread pool.
+///
+/// Groups of 8 bytes are encoded independently, so the result is exactly the same as
+/// [encode](crate::encode). It pays off only for large inputs (several MBs).
+pub fn encode_parallel(content: &[u8]) -> String {
+    let mut slice = vec![0; compute_encoded_size(content.len())];
+    let complete_groups_length = content.len() >> 3 << 3;
+    let (complete_slice, last_slice) = slice.split_at_mut((complete_groups_length >> 3) * 11);
+
+    // Complete groups.
+    content[..complete_groups_length]
+        .par_chunks(PARALLEL_BLOCK_GROUPS * 8)
+        .zip(complete_slice.par_chunks_mut(PARALLEL_BLOCK_GROUPS * 11))
+        .for_each(|(block, encoded)| dispatch::encode_groups(block, encoded));
+
+    // Last incomplete group.
+    if !last_slice.is_empty() {
+        let encoded = encoding::compute_chunk(&content[complete_groups_length..]);
+
+        last_slice.copy_from_slice(&encoded[..last_slice.len()]);
+    }
+
+    // SAFETY: every element of the zeroed slice has been overwritten with an encoded char,
+    // and both are ASCII characters.
+    unsafe { String::from_utf8_unchecked(slice) }
+}
+
+/// Decodes a G60 encoded string splitting the work across the rayon thread pool.
+///
+/// Groups of 11 chars are decoded independently, so the result is exactly the same as
+/// [decode](crate::decode), including the reported error: when several groups are invalid,
+/// the error of the earliest one is returned, with its index relative to the whole string.
+/// It pays off only for large inputs (several MBs).
+pub fn decode_parallel(encoded: &str) -> Result<Vec<u8>, DecodingError> {
+    let bytes = encoded.as_bytes();
+
+    // Check length.
+    let last_group_length = bytes.len() - bytes.len() / 11 * 11;
+    if let 1 | 4 | 8 = last_group_length {
+        return Err(DecodingError::Verification(
+            VerificationError::InvalidLength,
+        ));
+    }
+
+    let mut slice = vec![0; compute_decoded_size(bytes.len())];
+    let complete_groups_length = bytes.len() - last_group_length;
+    let (complete_slice, last_slice) = slice.split_at_mut(complete_groups_length / 11 * 8);
+
+    // Complete groups.
+    let first_error = bytes[..complete_groups_length]
+        .par_chunks(PARALLEL_BLOCK_GROUPS * 11)
+        .zip(complete_slice.par_chunks_mut(PARALLEL_BLOCK_GROUPS * 8))
+        .enumerate()
+        .filter_map(|(block_index, (block, decoded))| {
+            let chunk_index = block_index * PARALLEL_BLOCK_GROUPS * 11;
+
+            dispatch::decode_groups(chunk_index, block, decoded)
+                .err()
+                .map(|error| (block_index, error))
+        })
+        .min_by_key(|(block_index, _)| *block_index);
+
+    if let Some((_, error)) = first_error {
+        return Err(DecodingError::Verification(error));
+    }
+
+    // Last incomplete group.
+    if last_group_length != 0 {
+        let chunk = &bytes[complete_groups_length..];
+        let decoded = decoding::compute_chunk(complete_groups_length, chunk)?;
+
+        if decoded[last_slice.len()..].iter().any(|v| *v != 0) {
+            return Err(DecodingError::Verification(VerificationError::NotCanonical));
+        }
+
+        last_slice.copy_from_slice(&decoded[..last_slice.len()]);
+    }
+
+    Ok(slice)
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    #[test]
+    fn test_encode_parallel() {
+        for length in [0, 1, 7, 8, 13, PARALLEL_BLOCK_GROUPS * 8 * 3 + 5] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+
+            assert_eq!(
+                encode_parallel(&bytes),
+                encode(&bytes),
+                "Incorrect for length {length}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_parallel() {
+        for length in [0, 1, 7, 8, 13, PARALLEL_BLOCK_GROUPS * 8 * 3 + 5] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = encode(&bytes);
+
+            assert_eq!(
+                decode_parallel(&encoded).expect("The decoding must succeed"),
+                bytes,
+                "Incorrect for length {length}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_parallel_reports_first_error() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(PARALLEL_BLOCK_GROUPS * 8 * 3)
+            .collect();
+        let mut encoded = encode(&bytes).into_bytes();
+        encoded[PARALLEL_BLOCK_GROUPS * 11 * 2 + 7] = b'O';
+        encoded[PARALLEL_BLOCK_GROUPS * 11 + 3] = b'I';
+        let encoded = String::from_utf8(encoded).unwrap();
+
+        let error = decode_parallel(&encoded).expect_err("The decoding cannot succeed");
+
+        assert_eq!(
+            error,
+            DecodingError::Verification(VerificationError::InvalidByte {
+                index: PARALLEL_BLOCK_GROUPS * 11 + 3,
+                byte: b'I',
+            }),
+            "Incorrect error"
+        );
+        assert_eq!(Err(error), decode(&encoded), "Different from sequential");
+
+        for encoded in ["Gt4CGFiHehzRzjCF1", "Gt4CGFiHehzRzjCF1z"] {
+            assert_eq!(
+                decode_parallel(encoded),
+                decode(encoded),
+                "Incorrect for '{}'",
+                encoded
+            );
+        }
+    }
+}
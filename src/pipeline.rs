@@ -0,0 +1,326 @@
+use std::collections::BTreeMap;
+use std::io::{ErrorKind, Read, Write};
+use std::sync::mpsc::sync_channel;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::decoding::{compute_decoded_size, decode_last_group};
+use crate::dispatch;
+use crate::encode;
+use crate::errors::{PipelineError, VerificationError};
+
+/// Number of complete groups of each block sent to the workers.
+const PIPELINE_BLOCK_GROUPS: usize = 8 * 1024;
+
+/// Encodes the content of `reader` into a G60 encoding format and writes it into `writer`,
+/// returning the number of elements written.
+///
+/// The content is read in a separate thread and split into group-aligned blocks that are
+/// encoded by `workers` threads, while the calling thread writes the results in order.
+/// Intended for streams (pipes, sockets) where the whole content is not in memory.
+///
+/// On an error the pipeline stops reading new blocks, but the reading thread is always
+/// joined before returning. If it is blocked in a `read` call, e.g. on a pipe or a socket
+/// whose peer is idle, the error is not returned until that call completes, so callers
+/// that need a prompt abort must configure a read timeout on the reader.
+///
+/// # Errors
+/// An error will be thrown if the reading or writing process fails.
+pub fn encode_reader_to_writer<R: Read + Send, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    workers: usize,
+) -> Result<usize, PipelineError> {
+    let block_size = PIPELINE_BLOCK_GROUPS * 8;
+
+    run_pipeline(reader, writer, workers, block_size, |_, block| {
+        Ok(encode(block).into_bytes())
+    })
+}
+
+/// Decodes the G60 encoded string read from `reader` and writes the result into `writer`,
+/// returning the number of elements written.
+///
+/// The encoded string is read in a separate thread and split into group-aligned blocks that
+/// are decoded by `workers` threads, while the calling thread writes the results in order.
+/// Intended for streams (pipes, sockets) where the whole content is not in memory.
+///
+/// On an error the pipeline stops reading new blocks, but the reading thread is always
+/// joined before returning. If it is blocked in a `read` call, e.g. on a pipe or a socket
+/// whose peer is idle, the error is not returned until that call completes, so callers
+/// that need a prompt abort must configure a read timeout on the reader.
+///
+/// # Errors
+/// An error will be thrown in the following cases:
+/// - if the reading or writing process fails.
+/// - if the read string is not a valid G60 encoded string, with indexes relative to the
+///   whole string. The blocks previous to the invalid one are already written.
+pub fn decode_reader_to_writer<R: Read + Send, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    workers: usize,
+) -> Result<usize, PipelineError> {
+    let block_size = PIPELINE_BLOCK_GROUPS * 11;
+
+    run_pipeline(reader, writer, workers, block_size, decode_block)
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Processes the content of `reader` in blocks of `block_size` elements across `workers`
+/// threads and writes the results in order. `process` receives the offset of each block.
+fn run_pipeline<R, W, F>(
+    reader: &mut R,
+    writer: &mut W,
+    workers: usize,
+    block_size: usize,
+    process: F,
+) -> Result<usize, PipelineError>
+where
+    R: Read + Send,
+    W: Write,
+    F: Fn(usize, &[u8]) -> Result<Vec<u8>, VerificationError> + Sync,
+{
+    let workers = workers.max(1);
+
+    thread::scope(|scope| {
+        let (job_sender, job_receiver) = sync_channel::<(usize, Vec<u8>)>(workers);
+        let (result_sender, result_receiver) = sync_channel(workers);
+
+        // Reader.
+        let reader_thread = scope.spawn(move || {
+            for index in 0.. {
+                let block = read_block(reader, block_size)?;
+                let is_last = block.len() < block_size;
+
+                if block.is_empty() || job_sender.send((index, block)).is_err() || is_last {
+                    break;
+                }
+            }
+
+            Ok(())
+        });
+
+        // Workers. Dropping the last reference to the job receiver stops the reader.
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+        for _ in 0..workers {
+            let job_receiver = job_receiver.clone();
+            let result_sender = result_sender.clone();
+            let process = &process;
+
+            scope.spawn(move || loop {
+                let job = job_receiver.lock().unwrap().recv();
+                let Ok((index, block)) = job else {
+                    break;
+                };
+
+                let result = process(index * block_size, &block);
+                if result_sender.send((index, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(job_receiver);
+        drop(result_sender);
+
+        // Writer.
+        let mut pending = BTreeMap::new();
+        let mut next_index = 0;
+        let mut written = 0;
+        for (index, result) in result_receiver {
+            pending.insert(index, result);
+
+            while let Some(result) = pending.remove(&next_index) {
+                let block = result.map_err(PipelineError::Verification)?;
+
+                writer.write_all(&block).map_err(PipelineError::Writing)?;
+                written += block.len();
+                next_index += 1;
+            }
+        }
+
+        reader_thread
+            .join()
+            .unwrap()
+            .map_err(PipelineError::Reading)?;
+
+        Ok(written)
+    })
+}
+
+/// Reads until `block_size` elements are read or the reader ends.
+fn read_block<R: Read>(reader: &mut R, block_size: usize) -> std::io::Result<Vec<u8>> {
+    let mut block = vec![0; block_size];
+    let mut block_length = 0;
+
+    while block_length < block_size {
+        match reader.read(&mut block[block_length..]) {
+            Ok(0) => break,
+            Ok(v) => block_length += v,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    block.truncate(block_length);
+    Ok(block)
+}
+
+/// Decodes a block of the encoded string, `chunk_index` being its offset in the whole string.
+fn decode_block(chunk_index: usize, block: &[u8]) -> Result<Vec<u8>, VerificationError> {
+    let last_group_length = block.len() % 11;
+    if let 1 | 4 | 8 = last_group_length {
+        return Err(VerificationError::InvalidLength);
+    }
+
+    let complete_groups_length = block.len() - last_group_length;
+    let mut slice = vec![0; compute_decoded_size(block.len())];
+    let (complete_slice, last_slice) = slice.split_at_mut(complete_groups_length / 11 * 8);
+
+    dispatch::decode_groups(
+        chunk_index,
+        &block[..complete_groups_length],
+        complete_slice,
+    )?;
+
+    let (decoded, _) = decode_last_group(
+        chunk_index + complete_groups_length,
+        &block[complete_groups_length..],
+    )?;
+    last_slice.copy_from_slice(&decoded[..last_slice.len()]);
+
+    Ok(slice)
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_reader_to_writer() {
+        for length in [
+            0,
+            1,
+            13,
+            PIPELINE_BLOCK_GROUPS * 8,
+            PIPELINE_BLOCK_GROUPS * 8 * 5 + 3,
+        ] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+
+            for workers in [0, 1, 4] {
+                let mut result = Vec::new();
+                let written = encode_reader_to_writer(&mut bytes.as_slice(), &mut result, workers)
+                    .expect("The encoding must succeed");
+
+                assert_eq!(written, result.len(), "Incorrect length for {length}");
+                assert_eq!(
+                    result,
+                    encode(&bytes).into_bytes(),
+                    "Incorrect for {length}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_reader_to_writer() {
+        for length in [
+            0,
+            1,
+            13,
+            PIPELINE_BLOCK_GROUPS * 8,
+            PIPELINE_BLOCK_GROUPS * 8 * 5 + 3,
+        ] {
+            let bytes: Vec<u8> = (0..=255u8).cycle().take(length).collect();
+            let encoded = encode(&bytes);
+
+            for workers in [0, 1, 4] {
+                let mut result = Vec::new();
+                let written =
+                    decode_reader_to_writer(&mut encoded.as_bytes(), &mut result, workers)
+                        .expect("The decoding must succeed");
+
+                assert_eq!(written, result.len(), "Incorrect length for {length}");
+                assert_eq!(result, bytes, "Incorrect for {length}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_reader_to_writer_errors() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(PIPELINE_BLOCK_GROUPS * 8 * 5)
+            .collect();
+        let mut encoded = encode(&bytes).into_bytes();
+        encoded[PIPELINE_BLOCK_GROUPS * 11 * 3 + 1] = b'O';
+        encoded[PIPELINE_BLOCK_GROUPS * 11 * 4 + 1] = b'I';
+
+        let mut result = Vec::new();
+        let error = decode_reader_to_writer(&mut encoded.as_slice(), &mut result, 4)
+            .expect_err("The decoding cannot succeed");
+
+        assert!(
+            matches!(
+                error,
+                PipelineError::Verification(VerificationError::InvalidByte { index, byte: b'O' })
+                    if index == PIPELINE_BLOCK_GROUPS * 11 * 3 + 1
+            ),
+            "Incorrect error {:?}",
+            error
+        );
+        assert_eq!(result, bytes[..PIPELINE_BLOCK_GROUPS * 8 * 3]);
+
+        let error = decode_reader_to_writer(&mut b"Gt4CGFiHehzRzjCF16x".as_slice(), &mut result, 2)
+            .expect_err("The decoding cannot succeed");
+
+        assert!(
+            matches!(
+                error,
+                PipelineError::Verification(VerificationError::InvalidLength)
+            ),
+            "Incorrect error {:?}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_writing_errors() {
+        let mut writer = [0; 10];
+        let error =
+            encode_reader_to_writer(&mut [0; 100].as_slice(), &mut writer.as_mut_slice(), 2)
+                .expect_err("The encoding cannot succeed");
+
+        assert!(
+            matches!(&error, PipelineError::Writing(e) if e.kind() == ErrorKind::WriteZero),
+            "Incorrect error {:?}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_reading_errors() {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("connection reset"))
+            }
+        }
+
+        let error = encode_reader_to_writer(&mut FailingReader, &mut Vec::new(), 2)
+            .expect_err("The encoding cannot succeed");
+
+        assert!(
+            matches!(&error, PipelineError::Reading(e) if e.to_string() == "connection reset"),
+            "Incorrect error {:?}",
+            error
+        );
+    }
+}
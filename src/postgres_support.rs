@@ -0,0 +1,107 @@
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{G60Str, G60String};
+
+impl ToSql for &G60Str {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.as_str().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl ToSql for G60String {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.as_g60_str().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&G60Str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for &'a G60Str {
+    /// Reads a text column verifying it is a valid canonical G60 encoded string.
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(G60Str::new(<&str as FromSql>::from_sql(ty, raw)?)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl<'a> FromSql<'a> for G60String {
+    /// Reads a text column verifying it is a valid canonical G60 encoded string.
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(<&G60Str as FromSql>::from_sql(ty, raw)?.to_owned())
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&G60Str as FromSql>::accepts(ty)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::VerificationError;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let encoded = G60String::encode(b"Hello, world!");
+
+        for ty in [Type::TEXT, Type::VARCHAR] {
+            let mut buffer = BytesMut::new();
+            encoded
+                .to_sql_checked(&ty, &mut buffer)
+                .expect("The writing must succeed");
+
+            assert_eq!(&buffer[..], b"Gt4CGFiHehzRzjCF16", "Incorrect for {}", ty);
+            assert_eq!(
+                G60String::from_sql(&ty, &buffer).expect("The reading must succeed"),
+                encoded,
+                "Incorrect for {}",
+                ty
+            );
+        }
+
+        assert!(encoded
+            .to_sql_checked(&Type::BYTEA, &mut BytesMut::new())
+            .is_err());
+        assert!(!<G60String as FromSql>::accepts(&Type::INT4));
+    }
+
+    #[test]
+    fn test_from_sql_errors() {
+        let error = G60String::from_sql(&Type::TEXT, b"Gt4CGFiHehzRzjCF17")
+            .expect_err("The reading must fail");
+
+        assert_eq!(
+            error.downcast_ref::<VerificationError>(),
+            Some(&VerificationError::NotCanonical)
+        );
+    }
+}
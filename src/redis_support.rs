@@ -0,0 +1,95 @@
+use redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+use crate::{G60Str, G60String};
+
+impl ToRedisArgs for &G60Str {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.as_str().as_bytes())
+    }
+}
+
+impl ToRedisArgs for G60String {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.as_g60_str().write_redis_args(out)
+    }
+}
+
+impl FromRedisValue for G60String {
+    /// Reads a string response verifying it is a valid canonical G60 encoded string.
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        verify(String::from_redis_value(v)?)
+    }
+
+    fn from_owned_redis_value(v: Value) -> RedisResult<Self> {
+        verify(String::from_owned_redis_value(v)?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Verifies a string response is a valid canonical G60 encoded string.
+fn verify(encoded: String) -> RedisResult<G60String> {
+    G60String::new(encoded).map_err(|e| {
+        RedisError::from((
+            ErrorKind::TypeError,
+            "Response is not a valid G60 encoded string",
+            e.to_string(),
+        ))
+    })
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_redis_args() {
+        let encoded = G60String::encode(b"Hello, world!");
+
+        assert_eq!(
+            encoded.to_redis_args(),
+            vec![b"Gt4CGFiHehzRzjCF16".to_vec()]
+        );
+        assert_eq!(
+            encoded.as_g60_str().to_redis_args(),
+            vec![b"Gt4CGFiHehzRzjCF16".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_from_redis_value() {
+        let value = Value::BulkString(b"Gt4CGFiHehzRzjCF16".to_vec());
+
+        assert_eq!(
+            G60String::from_redis_value(&value).expect("The conversion must succeed"),
+            G60String::encode(b"Hello, world!")
+        );
+
+        for value in [
+            Value::BulkString(b"Gt4CGFiHehzRzjCF17".to_vec()),
+            Value::Okay,
+            Value::Nil,
+        ] {
+            let error = G60String::from_redis_value(&value).expect_err("The conversion must fail");
+
+            assert_eq!(
+                error.kind(),
+                ErrorKind::TypeError,
+                "Incorrect for {:?}",
+                value
+            );
+        }
+    }
+}
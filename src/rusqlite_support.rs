@@ -0,0 +1,80 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::{G60Str, G60String};
+
+impl ToSql for G60Str {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.as_str()))
+    }
+}
+
+impl ToSql for G60String {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        self.as_g60_str().to_sql()
+    }
+}
+
+impl FromSql for G60String {
+    /// Reads a text column verifying it is a valid canonical G60 encoded string. The
+    /// verification errors are returned as [FromSqlError::Other].
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let encoded = value.as_str()?;
+
+        match G60Str::new(encoded) {
+            Ok(v) => Ok(v.to_owned()),
+            Err(e) => Err(FromSqlError::Other(Box::new(e))),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use crate::errors::VerificationError;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let connection = Connection::open_in_memory().expect("The connection must succeed");
+        let encoded = G60String::encode(b"Hello, world!");
+
+        let result: G60String = connection
+            .query_row("SELECT ?1", [&encoded], |row| row.get(0))
+            .expect("The query must succeed");
+        assert_eq!(result, encoded);
+
+        let result: G60String = connection
+            .query_row("SELECT ?1", [encoded.as_g60_str()], |row| row.get(0))
+            .expect("The query must succeed");
+        assert_eq!(result, encoded);
+    }
+
+    #[test]
+    fn test_column_result_errors() {
+        let connection = Connection::open_in_memory().expect("The connection must succeed");
+
+        let error = connection
+            .query_row("SELECT 'Gt4CGFiHehzRzjCF17'", [], |row| {
+                row.get::<_, G60String>(0)
+            })
+            .expect_err("The query must fail");
+        match error {
+            rusqlite::Error::FromSqlConversionFailure(_, _, e) => assert_eq!(
+                e.downcast_ref::<VerificationError>(),
+                Some(&VerificationError::NotCanonical)
+            ),
+            e => panic!("Unexpected error: {:?}", e),
+        }
+
+        let error = connection
+            .query_row("SELECT 5", [], |row| row.get::<_, G60String>(0))
+            .expect_err("The query must fail");
+        assert!(matches!(error, rusqlite::Error::InvalidColumnType(..)));
+    }
+}
@@ -0,0 +1,53 @@
+use secrecy::{CloneableSecret, SecretBox};
+
+use crate::G60String;
+
+/// A [G60String] holding a secret value, like a session token or a key. Its [Debug]
+/// output is redacted and it is zeroed on drop, so its content is only reachable, and
+/// decodable, through [ExposeSecret::expose_secret](secrecy::ExposeSecret::expose_secret).
+///
+/// ```rust
+/// # use g60::G60String;
+/// use secrecy::ExposeSecret;
+///
+/// let secret = G60String::encode(b"Hello, world!").into_secret();
+///
+/// assert!(format!("{:?}", secret).ends_with("([REDACTED])"));
+/// assert_eq!(secret.expose_secret().decode(), b"Hello, world!");
+/// ```
+pub type SecretG60String = SecretBox<G60String>;
+
+impl G60String {
+    // METHODS ----------------------------------------------------------------
+
+    /// Moves the encoded string into a [SecretG60String].
+    pub fn into_secret(self) -> SecretG60String {
+        SecretBox::new(Box::new(self))
+    }
+}
+
+impl CloneableSecret for G60String {}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::ExposeSecret;
+
+    #[test]
+    fn test_secret() {
+        let secret = G60String::encode(b"Hello, world!").into_secret();
+        let debug = format!("{:?}", secret.clone());
+
+        assert!(
+            !debug.contains("Gt4CGFiHehzRzjCF16"),
+            "Incorrect for '{}'",
+            debug
+        );
+        assert_eq!(secret.expose_secret(), "Gt4CGFiHehzRzjCF16");
+        assert_eq!(secret.expose_secret().decode(), b"Hello, world!");
+    }
+}
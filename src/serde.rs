@@ -0,0 +1,334 @@
+//! Helpers to (de)serialize byte fields as G60 encoded strings with
+//! `#[serde(with = "g60::serde")]`.
+//!
+//! Any field that implements `AsRef<[u8]>` can be serialized, and any field that
+//! implements `TryFrom<Vec<u8>>`, like `Vec<u8>`, `Box<[u8]>` or `[u8; N]`, can be
+//! deserialized. Borrowed `&[u8]` fields cannot be deserialized because the decoded bytes
+//! are not in the input, so use `#[serde(serialize_with = "g60::serde::serialize")]`
+//! for them.
+//!
+//! Like the serde implementations of [G60String](crate::G60String), the encoded string is
+//! only used in human-readable formats. Binary formats get the raw bytes. The [string]
+//! module keeps the encoded string of a [G60String](crate::G60String) in every format.
+//!
+//! ```rust
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "g60::serde")]
+//!     key: [u8; 8],
+//!     #[serde(with = "g60::serde")]
+//!     salt: Vec<u8>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"key":"Gt4CGFiHehz","salt":"U0"}"#).unwrap();
+//!
+//! assert_eq!(&config.key, b"Hello, w");
+//! assert_eq!(config.salt, b"x");
+//! ```
+//!
+//! With the `serde_with` feature, [G60] adapts the same (de)serialization to the
+//! [serde_with](https://crates.io/crates/serde_with) traits, so it composes with other
+//! types: `#[serde_as(as = "Option<g60::serde::G60>")]`.
+
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use ::serde::de::{Error, Visitor};
+use ::serde::{Deserializer, Serializer};
+
+use crate::encoding::compute_encoded_size;
+use crate::{decode, encode_in_slice_str};
+
+/// Serializes `value` as a G60 encoded string.
+pub fn serialize<T: AsRef<[u8]> + ?Sized, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let value = value.as_ref();
+
+    if !serializer.is_human_readable() {
+        return serializer.serialize_bytes(value);
+    }
+
+    // Short values are encoded without allocating.
+    let mut buffer = [0; 64];
+    if compute_encoded_size(value.len()) <= buffer.len() {
+        return serializer.serialize_str(encode_in_slice_str(value, &mut buffer).unwrap());
+    }
+
+    serializer.serialize_str(&crate::encode(value))
+}
+
+/// Deserializes a G60 encoded string into `T`.
+///
+/// # Errors
+/// An error will be thrown if the input is not a valid canonical G60 encoded string or its
+/// decoded content cannot be converted into `T`, e.g. if it does not have the length of
+/// an array.
+pub fn deserialize<'de, T: TryFrom<Vec<u8>>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_byte_buf(BytesVisitor(PhantomData))
+    }
+}
+
+/// An adapter for `#[serde_as(as = "...")]` of the
+/// [serde_with](https://crates.io/crates/serde_with) crate, which (de)serializes the same
+/// types as the functions of this module but can be nested inside `Option`, `Vec`, maps,
+/// etc.
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::serde_as;
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde_as(as = "Option<g60::serde::G60>")]
+///     signature: Option<Vec<u8>>,
+///     #[serde_as(as = "Vec<g60::serde::G60>")]
+///     keys: Vec<[u8; 2]>,
+/// }
+///
+/// let message = Message {
+///     signature: None,
+///     keys: vec![[0, 0], [255, 255]],
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_string(&message).unwrap(),
+///     r#"{"signature":null,"keys":["000","zil"]}"#
+/// );
+/// ```
+#[cfg(feature = "serde_with")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct G60;
+
+#[cfg(feature = "serde_with")]
+impl<T: AsRef<[u8]>> serde_with::SerializeAs<T> for G60 {
+    fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(source, serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<'de, T: TryFrom<Vec<u8>>> serde_with::DeserializeAs<'de, T> for G60 {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+/// Helpers to (de)serialize a [G60String](crate::G60String) field as its encoded string in
+/// every format with `#[serde(with = "g60::serde::string")]`, even in the non-human-readable
+/// ones where its serde implementations use the decoded bytes, so the stored type does not
+/// depend on the serializer.
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use g60::G60String;
+/// #[derive(Serialize, Deserialize)]
+/// struct Document {
+///     #[serde(with = "g60::serde::string")]
+///     id: G60String,
+/// }
+/// ```
+pub mod string {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::serialization::G60StringVisitor;
+    use crate::{G60Str, G60String};
+
+    /// Serializes `value` as its encoded string.
+    pub fn serialize<T: AsRef<G60Str> + ?Sized, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value.as_ref().as_str())
+    }
+
+    /// Deserializes an encoded string into a [G60String]. Bytes are also accepted, as
+    /// stored by its serde implementations in non-human-readable formats.
+    ///
+    /// # Errors
+    /// An error will be thrown if the input is not a valid canonical G60 encoded string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<G60String, D::Error> {
+        deserializer.deserialize_string(G60StringVisitor)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+struct BytesVisitor<T>(PhantomData<T>);
+
+impl<T: TryFrom<Vec<u8>>> Visitor<'_> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a canonical G60 encoded string or bytes")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.visit_byte_buf(decode(v).map_err(E::custom)?)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        self.visit_byte_buf(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        let length = v.len();
+
+        T::try_from(v).map_err(|_| {
+            E::custom(format_args!(
+                "unexpected decoded length {} for the field",
+                length
+            ))
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+    struct Owned {
+        #[serde(with = "crate::serde")]
+        vector: Vec<u8>,
+        #[serde(with = "crate::serde")]
+        boxed: Box<[u8]>,
+        #[serde(with = "crate::serde")]
+        array: [u8; 13],
+    }
+
+    #[derive(Serialize)]
+    struct Borrowed<'a> {
+        #[serde(serialize_with = "crate::serde::serialize")]
+        slice: &'a [u8],
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let long: Vec<u8> = (0..=255u8).cycle().take(100).collect();
+        let value = Owned {
+            vector: long.clone(),
+            boxed: b"".to_vec().into_boxed_slice(),
+            array: *b"Hello, world!",
+        };
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"vector":"{}","boxed":"","array":"Gt4CGFiHehzRzjCF16"}}"#,
+                crate::encode(&long)
+            )
+        );
+        assert_eq!(serde_json::from_str::<Owned>(&json).unwrap(), value);
+
+        let borrowed = Borrowed {
+            slice: b"Hello, world!",
+        };
+        assert_eq!(
+            serde_json::to_string(&borrowed).unwrap(),
+            r#"{"slice":"Gt4CGFiHehzRzjCF16"}"#
+        );
+    }
+
+    #[cfg(feature = "serde_with")]
+    #[test]
+    fn test_serde_with() {
+        use serde_with::serde_as;
+        use std::collections::BTreeMap;
+
+        #[serde_as]
+        #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+        struct Nested {
+            #[serde_as(as = "Option<crate::serde::G60>")]
+            optional: Option<Vec<u8>>,
+            #[serde_as(as = "Vec<crate::serde::G60>")]
+            list: Vec<[u8; 13]>,
+            #[serde_as(as = "BTreeMap<_, crate::serde::G60>")]
+            map: BTreeMap<String, Box<[u8]>>,
+        }
+
+        let value = Nested {
+            optional: Some(b"Hello, world!".to_vec()),
+            list: vec![*b"Hello, world!"],
+            map: BTreeMap::from([("key".to_string(), b"Hello, world!".to_vec().into())]),
+        };
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            r#"{"optional":"Gt4CGFiHehzRzjCF16","list":["Gt4CGFiHehzRzjCF16"],"map":{"key":"Gt4CGFiHehzRzjCF16"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Nested>(&json).unwrap(), value);
+
+        let test = r#"{"optional":null,"list":["Gt4CGFiHehz"],"map":{}}"#;
+        assert!(
+            serde_json::from_str::<Nested>(test).is_err(),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    #[test]
+    fn test_human_readable() {
+        use serde_test::{assert_tokens, Configure, Token};
+
+        #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "crate::serde")] [u8; 13]);
+
+        let value = || Wrapper(*b"Hello, world!");
+        let tokens = |value| [Token::NewtypeStruct { name: "Wrapper" }, value];
+
+        assert_tokens(
+            &value().readable(),
+            &tokens(Token::Str("Gt4CGFiHehzRzjCF16")),
+        );
+        assert_tokens(&value().compact(), &tokens(Token::Bytes(b"Hello, world!")));
+    }
+
+    #[test]
+    fn test_string() {
+        use serde_test::{assert_tokens, Configure, Token};
+
+        #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "crate::serde::string")] crate::G60String);
+
+        let value = || Wrapper(crate::G60String::encode(b"Hello, world!"));
+        let tokens = [
+            Token::NewtypeStruct { name: "Wrapper" },
+            Token::Str("Gt4CGFiHehzRzjCF16"),
+        ];
+
+        assert_tokens(&value().readable(), &tokens);
+        assert_tokens(&value().compact(), &tokens);
+        assert!(serde_json::from_str::<Wrapper>(r#""Gt4CGFiHehzRzjCF17""#).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        for test in [
+            r#"{"vector":"Gt4CGFiHehzRzjCF17","boxed":"","array":"Gt4CGFiHehzRzjCF16"}"#,
+            r#"{"vector":"","boxed":"","array":"Gt4CGFiHehz"}"#,
+            r#"{"vector":1,"boxed":"","array":"Gt4CGFiHehzRzjCF16"}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<Owned>(test).is_err(),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+}
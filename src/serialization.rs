@@ -0,0 +1,150 @@
+use std::fmt::Formatter;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{G60Str, G60String};
+
+impl Serialize for G60Str {
+    /// Serializes the encoded string in human-readable formats, like JSON or TOML, and the
+    /// decoded bytes in binary formats, like bincode or CBOR, to avoid the size penalty of
+    /// a text encoding inside a binary format.
+    ///
+    /// The choice is made by [Serializer::is_human_readable], so some formats that are
+    /// usually seen as text-like store the bytes instead, e.g. the raw BSON serializer used
+    /// by `bson::to_vec` and the MongoDB driver. Use [g60::serde::string](crate::serde::string)
+    /// to get the encoded string in every format.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_str())
+        } else {
+            serializer.serialize_bytes(&self.decode())
+        }
+    }
+}
+
+impl Serialize for G60String {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_g60_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for G60String {
+    /// Deserializes a string verifying it is a valid canonical G60 encoded string in
+    /// human-readable formats, and the decoded bytes in binary formats.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_string(G60StringVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(G60StringVisitor)
+        }
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for &'a G60Str {
+    /// Deserializes a borrowed string verifying it is a valid canonical G60 encoded string.
+    /// Binary formats are not supported because they contain the decoded bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return Err(D::Error::custom(
+                "a borrowed G60Str cannot be deserialized from a binary format",
+            ));
+        }
+
+        let encoded = <&'a str>::deserialize(deserializer)?;
+
+        G60Str::new(encoded).map_err(D::Error::custom)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+pub(crate) struct G60StringVisitor;
+
+impl Visitor<'_> for G60StringVisitor {
+    type Value = G60String;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a canonical G60 encoded string or bytes")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        G60String::try_from(v).map_err(E::custom)
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        G60String::new(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(G60String::encode(v))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    #[test]
+    fn test_serialize() {
+        let encoded = G60String::encode(b"Hello, world!");
+
+        assert_eq!(
+            serde_json::to_string(&encoded).unwrap(),
+            "\"Gt4CGFiHehzRzjCF16\""
+        );
+        assert_eq!(
+            serde_json::to_string(encoded.as_g60_str()).unwrap(),
+            "\"Gt4CGFiHehzRzjCF16\""
+        );
+    }
+
+    #[test]
+    fn test_human_readable() {
+        let encoded = G60String::encode(b"Hello, world!");
+
+        assert_tokens(
+            &encoded.clone().readable(),
+            &[Token::Str("Gt4CGFiHehzRzjCF16")],
+        );
+        assert_tokens(&encoded.compact(), &[Token::Bytes(b"Hello, world!")]);
+        assert_de_tokens_error::<serde_test::Compact<&G60Str>>(
+            &[Token::BorrowedBytes(b"Hello, world!")],
+            "a borrowed G60Str cannot be deserialized from a binary format",
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let test = "\"Gt4CGFiHehzRzjCF16\"";
+
+        assert_eq!(
+            serde_json::from_str::<G60String>(test).unwrap(),
+            G60String::encode(b"Hello, world!")
+        );
+        assert_eq!(
+            serde_json::from_str::<&G60Str>(test).unwrap(),
+            "Gt4CGFiHehzRzjCF16"
+        );
+
+        for test in ["\"Gt4CGFiHehzRzjCF17\"", "\"Hello\"", "12"] {
+            assert!(
+                serde_json::from_str::<G60String>(test).is_err(),
+                "Incorrect for '{}'",
+                test
+            );
+            assert!(
+                serde_json::from_str::<&G60Str>(test).is_err(),
+                "Incorrect for '{}'",
+                test
+            );
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type};
+
+use crate::G60String;
+
+impl<DB: Database> Type<DB> for G60String
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for G60String
+where
+    String: Encode<'q, DB>,
+{
+    fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        <String as Encode<'q, DB>>::encode(self.into_string(), buf)
+    }
+
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        <String as Encode<'q, DB>>::encode(self.as_str().to_owned(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for G60String
+where
+    String: Decode<'r, DB>,
+{
+    /// Decodes a text column verifying it is a valid canonical G60 encoded string.
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let encoded = <String as Decode<'r, DB>>::decode(value)?;
+
+        Ok(G60String::new(encoded)?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Connection, SqliteConnection};
+
+    use crate::errors::VerificationError;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sqlite() {
+        let mut connection = SqliteConnection::connect("sqlite::memory:")
+            .await
+            .expect("The connection must succeed");
+        let encoded = G60String::encode(b"Hello, world!");
+
+        let result: G60String = sqlx::query_scalar("SELECT ?")
+            .bind(&encoded)
+            .fetch_one(&mut connection)
+            .await
+            .expect("The query must succeed");
+        assert_eq!(result, encoded);
+
+        let result: Result<G60String, _> = sqlx::query_scalar("SELECT 'Gt4CGFiHehzRzjCF17'")
+            .fetch_one(&mut connection)
+            .await;
+        let error = match result {
+            Err(sqlx::Error::ColumnDecode { source, .. }) => source,
+            v => panic!("Unexpected result: {:?}", v),
+        };
+        assert_eq!(
+            error.downcast_ref::<VerificationError>(),
+            Some(&VerificationError::NotCanonical)
+        );
+    }
+}
@@ -0,0 +1,614 @@
+use std::io::{ErrorKind, Write};
+
+use crate::constants::BLOCK_GROUPS;
+use crate::decoding::{compute_chunk, decode_last_group};
+use crate::dispatch;
+use crate::encode_config::{EncodeConfig, LineWrapper};
+use crate::encoding;
+use crate::errors::{DecodingError, EncodingError, VerificationError};
+use crate::utils::{scratch_buffer, ScratchBuffer, ScratchVec};
+
+/// A writer adapter that encodes the content written into it into a G60 encoding format
+/// and writes the result into the inner writer.
+///
+/// The content can be written in chunks of any size. [finish](EncoderWriter::finish) must
+/// be called at the end to encode the last group.
+///
+/// The encoded content the inner writer does not accept is kept and written first by the
+/// next call, so a write that fails has not consumed anything and can be retried. A
+/// failure after the content of a write is consumed is reported by the next call.
+///
+/// ```rust
+/// # use g60::{EncodeConfig, EncoderWriter};
+/// # use std::io::Write;
+/// let mut encoder = EncoderWriter::with_config(Vec::new(), EncodeConfig::new().wrap(8));
+/// encoder.write_all(b"Hello, ").unwrap();
+/// encoder.write_all(b"world!").unwrap();
+///
+/// assert_eq!(encoder.finish().unwrap(), b"Gt4CGFiH\nehzRzjCF\n16");
+/// ```
+#[derive(Debug)]
+pub struct EncoderWriter<W: Write> {
+    writer: W,
+    wrapper: LineWrapper,
+    prefix: Option<String>,
+    suffix: String,
+    group: ScratchBuffer<8>,
+    group_length: usize,
+    output: PendingOutput,
+}
+
+impl<W: Write> EncoderWriter<W> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds an encoder that writes into `writer`.
+    pub fn new(writer: W) -> EncoderWriter<W> {
+        Self::with_config(writer, EncodeConfig::new())
+    }
+
+    /// Builds an encoder that writes into `writer` following `config`.
+    pub fn with_config(writer: W, config: EncodeConfig) -> EncoderWriter<W> {
+        let wrapper = config.line_wrapper();
+        let (prefix, suffix) = config.into_tags();
+
+        EncoderWriter {
+            writer,
+            wrapper,
+            prefix: Some(prefix),
+            suffix,
+            group: scratch_buffer(),
+            group_length: 0,
+            output: PendingOutput::new(),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns the number of elements written into the inner writer so far.
+    pub fn written(&self) -> usize {
+        self.output.written
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Encodes the last group and returns the inner writer.
+    ///
+    /// # Errors
+    /// An error will be thrown if the writing process fails.
+    pub fn finish(mut self) -> Result<W, EncodingError> {
+        self.output.drain(&mut self.writer)?;
+        self.push_prefix();
+
+        if self.group_length != 0 {
+            let encoded = encoding::compute_chunk(&self.group[..self.group_length]);
+            let elements_to_write = encoding::compute_encoded_size(self.group_length);
+
+            self.wrapper
+                .write(self.output.buffer(), &encoded[..elements_to_write])?;
+        }
+
+        self.output
+            .buffer()
+            .extend_from_slice(self.suffix.as_bytes());
+        self.output.drain(&mut self.writer)?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    // AUX METHODS ------------------------------------------------------------
+
+    fn push_prefix(&mut self) {
+        if let Some(prefix) = self.prefix.take() {
+            self.output.buffer().extend_from_slice(prefix.as_bytes());
+        }
+    }
+
+    /// Encodes the start of `buf` into the pending output, at most a pending group and a
+    /// block of groups at a time, returning the number of elements consumed.
+    fn encode_chunk(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.push_prefix();
+
+        // Pending group.
+        let mut consumed = 0;
+        if self.group_length != 0 {
+            consumed = buf.len().min(8 - self.group_length);
+            self.group[self.group_length..self.group_length + consumed]
+                .copy_from_slice(&buf[..consumed]);
+            self.group_length += consumed;
+
+            if self.group_length < 8 {
+                return Ok(consumed);
+            }
+
+            let encoded = encoding::compute_chunk(&self.group[..]);
+            self.wrapper.write(self.output.buffer(), &encoded)?;
+            self.group_length = 0;
+        }
+
+        // Complete groups.
+        let buf = &buf[consumed..];
+        let complete_groups_length = (buf.len() >> 3 << 3).min(BLOCK_GROUPS * 8);
+        if complete_groups_length != 0 {
+            let mut buffer = scratch_buffer::<{ BLOCK_GROUPS * 11 }>();
+            let encoded = &mut buffer[..(complete_groups_length >> 3) * 11];
+            dispatch::encode_groups(&buf[..complete_groups_length], encoded);
+
+            self.wrapper.write(self.output.buffer(), encoded)?;
+            consumed += complete_groups_length;
+        }
+
+        // Last incomplete group.
+        let rest = &buf[complete_groups_length..];
+        if rest.len() < 8 {
+            self.group[..rest.len()].copy_from_slice(rest);
+            self.group_length = rest.len();
+            consumed += rest.len();
+        }
+
+        Ok(consumed)
+    }
+}
+
+impl<W: Write> Write for EncoderWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.output.drain(&mut self.writer)?;
+
+        let consumed = self.encode_chunk(buf)?;
+
+        // The content is already consumed, so a failure is reported by the next call.
+        let _ = self.output.drain(&mut self.writer);
+
+        Ok(consumed)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.output.drain(&mut self.writer)?;
+        self.writer.flush()
+    }
+}
+
+/// A writer adapter that decodes the G60 encoded string written into it and writes the
+/// result into the inner writer.
+///
+/// The encoded string can be written in chunks of any size. Errors report the position of
+/// the invalid byte relative to everything written so far, and after the first one every
+/// following write fails with it too. [finish](DecoderWriter::finish) must be called at the
+/// end to decode the last group.
+///
+/// Like in [EncoderWriter], the decoded content the inner writer does not accept is kept
+/// and written first by the next call, so a write that fails because of the inner writer
+/// can be retried.
+///
+/// ```rust
+/// # use g60::DecoderWriter;
+/// # use std::io::Write;
+/// let mut decoder = DecoderWriter::new(Vec::new());
+/// decoder.write_all(b"Gt4CGFiHe").unwrap();
+/// decoder.write_all(b"hzRzjCF16").unwrap();
+///
+/// assert_eq!(decoder.finish().unwrap(), b"Hello, world!");
+/// ```
+#[derive(Debug)]
+pub struct DecoderWriter<W: Write> {
+    writer: W,
+    group: [u8; 11],
+    group_length: usize,
+    chunk_index: usize,
+    error: Option<VerificationError>,
+    output: PendingOutput,
+}
+
+impl<W: Write> DecoderWriter<W> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds a decoder that writes into `writer`.
+    pub fn new(writer: W) -> DecoderWriter<W> {
+        DecoderWriter {
+            writer,
+            group: [0; 11],
+            group_length: 0,
+            chunk_index: 0,
+            error: None,
+            output: PendingOutput::new(),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns the number of encoded elements written so far.
+    pub fn consumed(&self) -> usize {
+        self.chunk_index + self.group_length
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Decodes the last group and returns the inner writer.
+    ///
+    /// # Errors
+    /// An error will be thrown if the whole written string is not a valid canonical G60
+    /// encoded string or the writing process fails.
+    pub fn finish(mut self) -> Result<W, DecodingError> {
+        if let Some(error) = self.error {
+            return Err(DecodingError::Verification(error));
+        }
+
+        self.output
+            .drain(&mut self.writer)
+            .map_err(|e| DecodingError::WritingError(e.kind()))?;
+
+        let (decoded, length) =
+            decode_last_group(self.chunk_index, &self.group[..self.group_length])?;
+
+        self.output.buffer().extend_from_slice(&decoded[..length]);
+        self.output
+            .drain(&mut self.writer)
+            .map_err(|e| DecodingError::WritingError(e.kind()))?;
+        self.writer
+            .flush()
+            .map_err(|e| DecodingError::WritingError(e.kind()))?;
+
+        Ok(self.writer)
+    }
+
+    // AUX METHODS ------------------------------------------------------------
+
+    /// Decodes the start of `buf` into the pending output, at most a pending group and a
+    /// block of groups at a time, returning the number of elements consumed.
+    fn decode_chunk(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        // Pending group.
+        let mut consumed = 0;
+        if self.group_length != 0 {
+            consumed = buf.len().min(11 - self.group_length);
+            self.group[self.group_length..self.group_length + consumed]
+                .copy_from_slice(&buf[..consumed]);
+            self.group_length += consumed;
+
+            if self.group_length < 11 {
+                return Ok(consumed);
+            }
+
+            let decoded = compute_chunk(self.chunk_index, &self.group).map_err(|e| self.fail(e))?;
+            self.output.buffer().extend_from_slice(&decoded);
+            self.chunk_index += 11;
+            self.group_length = 0;
+        }
+
+        // Complete groups.
+        let buf = &buf[consumed..];
+        let complete_groups_length = (buf.len() / 11 * 11).min(BLOCK_GROUPS * 11);
+        if complete_groups_length != 0 {
+            let mut buffer = scratch_buffer::<{ BLOCK_GROUPS * 8 }>();
+            let decoded = &mut buffer[..complete_groups_length / 11 * 8];
+            dispatch::decode_groups(self.chunk_index, &buf[..complete_groups_length], decoded)
+                .map_err(|e| self.fail(e))?;
+
+            self.output.buffer().extend_from_slice(decoded);
+            self.chunk_index += complete_groups_length;
+            consumed += complete_groups_length;
+        }
+
+        // Last incomplete group.
+        let rest = &buf[complete_groups_length..];
+        if rest.len() < 11 {
+            self.group[..rest.len()].copy_from_slice(rest);
+            self.group_length = rest.len();
+            consumed += rest.len();
+        }
+
+        Ok(consumed)
+    }
+
+    fn fail(&mut self, error: VerificationError) -> std::io::Error {
+        self.error = Some(error);
+        to_io_error(error)
+    }
+}
+
+impl<W: Write> Write for DecoderWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Some(error) = self.error {
+            return Err(to_io_error(error));
+        }
+
+        self.output.drain(&mut self.writer)?;
+
+        let consumed = self.decode_chunk(buf)?;
+
+        // The content is already consumed, so a failure is reported by the next call.
+        let _ = self.output.drain(&mut self.writer);
+
+        Ok(consumed)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.output.drain(&mut self.writer)?;
+        self.writer.flush()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The output of a writer adapter that the inner writer has not accepted yet.
+#[derive(Debug)]
+struct PendingOutput {
+    buffer: ScratchVec,
+    start: usize,
+    written: usize,
+}
+
+impl PendingOutput {
+    fn new() -> PendingOutput {
+        PendingOutput {
+            buffer: ScratchVec::default(),
+            start: 0,
+            written: 0,
+        }
+    }
+
+    /// Returns the buffer to append new output to.
+    fn buffer(&mut self) -> &mut Vec<u8> {
+        &mut self.buffer
+    }
+
+    /// Writes the pending output into `writer`, keeping what it does not accept if it
+    /// fails.
+    fn drain<W: Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        while self.start < self.buffer.len() {
+            match writer.write(&self.buffer[self.start..]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(v) => {
+                    self.start += v;
+                    self.written += v;
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.buffer.clear();
+        self.start = 0;
+
+        Ok(())
+    }
+}
+
+fn to_io_error(error: VerificationError) -> std::io::Error {
+    std::io::Error::new(ErrorKind::InvalidData, DecodingError::Verification(error))
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    #[test]
+    fn test_encoder_writer() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(8 * BLOCK_GROUPS * 3 + 13)
+            .collect();
+        let encoded = encode(&bytes);
+
+        for chunk_size in [1, 3, 8, 100, bytes.len()] {
+            let mut encoder = EncoderWriter::new(Vec::new());
+            for chunk in bytes.chunks(chunk_size) {
+                encoder.write_all(chunk).expect("The encoding must succeed");
+            }
+
+            let result = encoder.finish().expect("The encoding must succeed");
+            assert_eq!(result, encoded.as_bytes(), "Incorrect for {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn test_encoder_writer_retries() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(8 * BLOCK_GROUPS * 3 + 13)
+            .collect();
+
+        for config in [
+            EncodeConfig::new(),
+            EncodeConfig::new().wrap(8),
+            EncodeConfig::new().prefix("<g60>").suffix("</g60>").wrap(8),
+        ] {
+            let encoded = config.encode(&bytes);
+
+            for max_length in [usize::MAX, 5] {
+                for chunk_size in [1, 3, 8, 100, bytes.len()] {
+                    let mut encoder =
+                        EncoderWriter::with_config(FlakyWriter::new(max_length), config.clone());
+                    for chunk in bytes.chunks(chunk_size) {
+                        write_with_retries(&mut encoder, chunk);
+                    }
+                    while encoder.flush().is_err() {}
+
+                    let result = encoder.finish().expect("The encoding must succeed");
+                    assert_eq!(
+                        result.content,
+                        encoded.as_bytes(),
+                        "Incorrect for {config:?} {max_length} {chunk_size}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decoder_writer() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(8 * BLOCK_GROUPS * 3 + 13)
+            .collect();
+        let encoded = encode(&bytes);
+
+        for chunk_size in [1, 3, 11, 100, encoded.len()] {
+            let mut decoder = DecoderWriter::new(Vec::new());
+            for chunk in encoded.as_bytes().chunks(chunk_size) {
+                decoder.write_all(chunk).expect("The decoding must succeed");
+            }
+
+            assert_eq!(
+                decoder.consumed(),
+                encoded.len(),
+                "Incorrect for {chunk_size}"
+            );
+
+            let result = decoder.finish().expect("The decoding must succeed");
+            assert_eq!(result, bytes, "Incorrect for {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn test_decoder_writer_retries() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(8 * BLOCK_GROUPS * 3 + 13)
+            .collect();
+        let encoded = encode(&bytes);
+
+        for max_length in [usize::MAX, 5] {
+            for chunk_size in [1, 3, 11, 100, encoded.len()] {
+                let mut decoder = DecoderWriter::new(FlakyWriter::new(max_length));
+                for chunk in encoded.as_bytes().chunks(chunk_size) {
+                    write_with_retries(&mut decoder, chunk);
+                }
+                while decoder.flush().is_err() {}
+
+                assert_eq!(
+                    decoder.consumed(),
+                    encoded.len(),
+                    "Incorrect for {max_length} {chunk_size}"
+                );
+
+                let result = decoder.finish().expect("The decoding must succeed");
+                assert_eq!(
+                    result.content, bytes,
+                    "Incorrect for {max_length} {chunk_size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_decoder_writer_errors() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(8 * BLOCK_GROUPS * 3 + 13)
+            .collect();
+        let mut encoded = encode(&bytes).into_bytes();
+        let index = 11 * BLOCK_GROUPS * 2 + 5;
+        encoded[index] = b'O';
+
+        for chunk_size in [1, 3, 11, 100, encoded.len()] {
+            let mut decoder = DecoderWriter::new(Vec::new());
+            let error = encoded
+                .chunks(chunk_size)
+                .find_map(|chunk| decoder.write_all(chunk).err())
+                .expect("The decoding cannot succeed");
+
+            let expected =
+                DecodingError::Verification(VerificationError::InvalidByte { index, byte: b'O' });
+
+            assert_eq!(
+                error.kind(),
+                ErrorKind::InvalidData,
+                "Incorrect for {chunk_size}"
+            );
+            assert_eq!(
+                error.into_inner().unwrap().downcast_ref::<DecodingError>(),
+                Some(&expected),
+                "Incorrect for {chunk_size}"
+            );
+            assert_eq!(
+                decoder.get_ref()[..],
+                bytes[..decoder.get_ref().len()],
+                "Incorrect for {chunk_size}"
+            );
+
+            // Aborted.
+            decoder
+                .write_all(b"0")
+                .expect_err("The decoding cannot succeed");
+            assert_eq!(
+                decoder.finish(),
+                Err(expected),
+                "Incorrect for {chunk_size}"
+            );
+        }
+
+        let test = "Gt4CGFiHehzRzjCF16x";
+        let mut decoder = DecoderWriter::new(Vec::new());
+        decoder
+            .write_all(test.as_bytes())
+            .expect("The write must succeed");
+        assert_eq!(
+            decoder.finish(),
+            Err(decode(test).unwrap_err()),
+            "Incorrect for '{}'",
+            test
+        );
+    }
+
+    /// A writer that accepts at most `max_length` bytes per write and whose first, second,
+    /// fourth and seventh writes fail.
+    struct FlakyWriter {
+        content: Vec<u8>,
+        calls: usize,
+        max_length: usize,
+    }
+
+    impl FlakyWriter {
+        fn new(max_length: usize) -> FlakyWriter {
+            FlakyWriter {
+                content: Vec::new(),
+                calls: 0,
+                max_length,
+            }
+        }
+    }
+
+    impl Write for FlakyWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.calls += 1;
+
+            if let 1 | 2 | 4 | 7 = self.calls {
+                return Err(ErrorKind::Other.into());
+            }
+
+            let length = buf.len().min(self.max_length);
+            self.content.extend_from_slice(&buf[..length]);
+            Ok(length)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Writes `buf` into `writer` retrying the failed writes.
+    fn write_with_retries<W: Write>(writer: &mut W, mut buf: &[u8]) {
+        while !buf.is_empty() {
+            if let Ok(length) = writer.write(buf) {
+                buf = &buf[length..];
+            }
+        }
+    }
+}
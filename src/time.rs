@@ -0,0 +1,110 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::errors::{DecodingError, EncodingError};
+use crate::{decode_i64, encode_i64};
+
+/// Encodes a time as a fixed-length G60 string of 11 chars that sorts in the same order as
+/// the times, e.g. to prefix object-store keys. The time is stored as the signed number of
+/// nanoseconds since the Unix epoch, like [encode_i64] does, so the times from 1677 to
+/// 2262 are representable.
+///
+/// ```rust
+/// # use std::time::{Duration, SystemTime, UNIX_EPOCH};
+/// let time = UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789);
+/// let encoded = g60::encode_system_time(time).unwrap();
+///
+/// assert_eq!(encoded.len(), 11);
+/// assert_eq!(g60::decode_system_time(&encoded).unwrap(), time);
+/// assert!(encoded < g60::encode_system_time(time + Duration::from_nanos(1)).unwrap());
+/// ```
+///
+/// # Errors
+/// An error will be thrown if `time` is not representable as an `i64` number of
+/// nanoseconds.
+pub fn encode_system_time(time: SystemTime) -> Result<String, EncodingError> {
+    let nanos = match time.duration_since(UNIX_EPOCH) {
+        Ok(v) => i64::try_from(v.as_nanos()),
+        // Negated as an i128 so `i64::MIN` is representable.
+        Err(e) => i64::try_from(-(e.duration().as_nanos() as i128)),
+    };
+
+    nanos.map(encode_i64).map_err(|_| EncodingError::OutOfRange)
+}
+
+/// Decodes a time encoded with [encode_system_time].
+///
+/// # Errors
+/// An error will be thrown if `encoded` does not have 11 chars or it is not a valid
+/// canonical G60 encoded string.
+pub fn decode_system_time(encoded: &str) -> Result<SystemTime, DecodingError> {
+    let nanos = decode_i64(encoded)?;
+    let duration = Duration::from_nanos(nanos.unsigned_abs());
+
+    if nanos < 0 {
+        Ok(UNIX_EPOCH - duration)
+    } else {
+        Ok(UNIX_EPOCH + duration)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let times = [
+            UNIX_EPOCH - Duration::from_nanos(i64::MIN.unsigned_abs()),
+            UNIX_EPOCH - Duration::from_nanos(i64::MAX as u64),
+            UNIX_EPOCH - Duration::from_secs(1),
+            UNIX_EPOCH - Duration::from_nanos(1),
+            UNIX_EPOCH,
+            UNIX_EPOCH + Duration::from_nanos(1),
+            UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789),
+            UNIX_EPOCH + Duration::from_nanos(i64::MAX as u64),
+        ];
+
+        for pair in times.windows(2) {
+            let encoded = encode_system_time(pair[0]).expect("The encoding must succeed");
+
+            assert_eq!(encoded.len(), 11, "Incorrect for {:?}", pair[0]);
+            assert!(
+                encoded < encode_system_time(pair[1]).unwrap(),
+                "Incorrect for {:?}",
+                pair[0]
+            );
+            assert_eq!(
+                decode_system_time(&encoded),
+                Ok(pair[0]),
+                "Incorrect for {:?}",
+                pair[0]
+            );
+        }
+
+        let now = SystemTime::now();
+        assert_eq!(
+            decode_system_time(&encode_system_time(now).unwrap()),
+            Ok(now)
+        );
+    }
+
+    #[test]
+    fn test_encode_out_of_range() {
+        for time in [
+            UNIX_EPOCH + Duration::from_nanos(i64::MAX as u64 + 1),
+            UNIX_EPOCH - Duration::from_nanos(i64::MIN.unsigned_abs() + 1),
+            UNIX_EPOCH + Duration::from_secs(u64::MAX >> 1),
+        ] {
+            assert_eq!(
+                encode_system_time(time),
+                Err(EncodingError::OutOfRange),
+                "Incorrect for {:?}",
+                time
+            );
+        }
+    }
+}
@@ -0,0 +1,664 @@
+//! Expiring tokens, like signed links or invite codes, rendered in G60.
+//!
+//! A [G60Token] packs an expiry timestamp followed by a fixed-size payload, e.g. a nonce
+//! or an identifier, so every token of the same type has the same encoded length.
+//!
+//! ```rust
+//! # use g60::token::G60Token;
+//! let token = G60Token::new(1_700_000_000, *b"invite-0042");
+//! let encoded = token.encode();
+//!
+//! assert_eq!(G60Token::decode(&encoded).unwrap(), token);
+//! assert!(token.is_expired_at(1_700_000_001));
+//! ```
+//!
+//! With the `getrandom` feature, `generate_token` generates random tokens in one call, and
+//! with the `rand_core` feature, `generate_token_with_rng` and `random_bytes_with_rng` do
+//! the same with any [rand](https://crates.io/crates/rand) compatible RNG, and
+//! `RandomG60Strings` generates them endlessly, and `random_with_prefix` generates them
+//! with a fixed prefix.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "rand_core")]
+use crate::constants::UTF8_TO_ENCODED_MAP;
+#[cfg(any(feature = "getrandom", feature = "rand_core"))]
+use crate::decoding::compute_decoded_size;
+#[cfg(any(feature = "getrandom", feature = "rand_core"))]
+use crate::encode_in_slice;
+#[cfg(feature = "rand_core")]
+use crate::encoding::compute_chunk as encoding_compute_chunk;
+#[cfg(any(feature = "getrandom", feature = "rand_core"))]
+use crate::encoding::compute_encoded_size;
+use crate::errors::DecodingError;
+#[cfg(feature = "rand_core")]
+use crate::errors::VerificationError;
+#[cfg(any(feature = "getrandom", feature = "rand_core"))]
+use crate::utils::scratch_buffer;
+use crate::{decode, decode_check, encode_check, G60String};
+
+/// The number of bytes of the expiry timestamp.
+const EXPIRY_LENGTH: usize = 8;
+
+/// A token made of an expiry timestamp, in seconds since the Unix epoch, and a payload
+/// of `N` bytes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct G60Token<const N: usize> {
+    expires_at: u64,
+    payload: [u8; N],
+}
+
+impl<const N: usize> G60Token<N> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds a token that expires at `expires_at` seconds since the Unix epoch.
+    pub fn new(expires_at: u64, payload: [u8; N]) -> G60Token<N> {
+        G60Token {
+            expires_at,
+            payload,
+        }
+    }
+
+    /// Decodes a token encoded with [G60Token::encode].
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical G60 encoded string or
+    /// its decoded content does not have the length of the token.
+    pub fn decode(encoded: &str) -> Result<G60Token<N>, DecodingError> {
+        Self::from_bytes(&decode(encoded)?)
+    }
+
+    /// Decodes a token encoded with [G60Token::encode_with_check], verifying its checksum.
+    ///
+    /// # Errors
+    /// An error will be thrown if `encoded` is not a valid canonical G60 encoded string, the
+    /// checksum does not match the content or the content does not have the length of the
+    /// token.
+    pub fn decode_with_check(encoded: &str) -> Result<G60Token<N>, DecodingError> {
+        Self::from_bytes(&decode_check(encoded)?)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the expiry timestamp in seconds since the Unix epoch.
+    pub fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    /// Returns the payload.
+    pub fn payload(&self) -> &[u8; N] {
+        &self.payload
+    }
+
+    /// Returns whether the token has expired according to the system clock.
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|v| v.as_secs())
+            .unwrap_or(0);
+
+        self.is_expired_at(now)
+    }
+
+    /// Returns whether the token has expired at `now` seconds since the Unix epoch. A
+    /// token expires at the exact second of its expiry timestamp.
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Encodes the token.
+    pub fn encode(&self) -> G60String {
+        G60String::encode(&self.to_bytes())
+    }
+
+    /// Encodes the token followed by its CRC-32 checksum, like [encode_check], so
+    /// [G60Token::decode_with_check] detects corrupted or altered tokens.
+    ///
+    /// The checksum is not a signature: anyone can compute it, so it does not prevent
+    /// forging tokens.
+    pub fn encode_with_check(&self) -> G60String {
+        // SAFETY: encode_check returns a valid canonical G60 encoded string.
+        unsafe { G60String::new_unchecked(encode_check(&self.to_bytes())) }
+    }
+
+    // AUX METHODS ------------------------------------------------------------
+
+    /// Serializes the big-endian expiry timestamp followed by the payload.
+    fn to_bytes(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(EXPIRY_LENGTH + N);
+        bytes.extend_from_slice(&self.expires_at.to_be_bytes());
+        bytes.extend_from_slice(&self.payload);
+
+        bytes
+    }
+
+    /// Deserializes the bytes written by [G60Token::to_bytes].
+    fn from_bytes(bytes: &[u8]) -> Result<G60Token<N>, DecodingError> {
+        if bytes.len() != EXPIRY_LENGTH + N {
+            return Err(DecodingError::UnexpectedDecodedLength {
+                expected: EXPIRY_LENGTH + N,
+                actual: bytes.len(),
+            });
+        }
+
+        let (expires_at, payload) = bytes.split_at(EXPIRY_LENGTH);
+
+        Ok(G60Token {
+            expires_at: u64::from_be_bytes(expires_at.try_into().unwrap()),
+            payload: payload.try_into().unwrap(),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Generates a random token with at least `entropy_bits` bits of entropy, filling the
+/// minimum number of bytes from the operating system's CSPRNG and encoding them.
+///
+/// ```rust
+/// let token = g60::token::generate_token(128);
+///
+/// assert_eq!(token.len(), 22);
+/// assert_eq!(token.decode().len(), 16);
+/// ```
+///
+/// # Panics
+/// Panics if the operating system's CSPRNG fails.
+#[cfg(feature = "getrandom")]
+pub fn generate_token(entropy_bits: u32) -> G60String {
+    let mut bytes = vec![0; entropy_bits.div_ceil(8) as usize];
+    getrandom::getrandom(&mut bytes).expect("The random generation must succeed");
+
+    G60String::encode(&bytes)
+}
+
+/// Fills `buffer` with a random canonical G60 string of the largest valid length that fits
+/// in it, taking the random bytes from the operating system's CSPRNG, and returns it. It
+/// does not allocate.
+///
+/// ```rust
+/// let mut buffer = [0; 24];
+/// let token = g60::token::random_into(&mut buffer);
+///
+/// assert_eq!(token.len(), 24);
+/// assert!(g60::verify(token).is_ok());
+/// ```
+///
+/// # Panics
+/// Panics if the operating system's CSPRNG fails.
+#[cfg(feature = "getrandom")]
+pub fn random_into(buffer: &mut [u8]) -> &str {
+    fill_random(buffer, |bytes| {
+        getrandom::getrandom(bytes).expect("The random generation must succeed")
+    })
+}
+
+/// Like [random_into] but filling the bytes from `rng`.
+#[cfg(feature = "rand_core")]
+pub fn random_into_with_rng<'a, R: rand_core::RngCore + ?Sized>(
+    buffer: &'a mut [u8],
+    rng: &mut R,
+) -> &'a str {
+    fill_random(buffer, |bytes| rng.fill_bytes(bytes))
+}
+
+/// Like `generate_token` but filling the bytes from `rng`, e.g. a seeded RNG in tests.
+#[cfg(feature = "rand_core")]
+pub fn generate_token_with_rng<R: rand_core::RngCore + ?Sized>(
+    entropy_bits: u32,
+    rng: &mut R,
+) -> G60String {
+    random_bytes_with_rng(entropy_bits.div_ceil(8) as usize, rng)
+}
+
+/// Fills `length` bytes from `rng` and encodes them.
+///
+/// ```rust
+/// # use rand::SeedableRng;
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+/// let encoded = g60::token::random_bytes_with_rng(16, &mut rng);
+///
+/// assert_eq!(encoded.decode().len(), 16);
+/// ```
+#[cfg(feature = "rand_core")]
+pub fn random_bytes_with_rng<R: rand_core::RngCore + ?Sized>(
+    length: usize,
+    rng: &mut R,
+) -> G60String {
+    let mut bytes = vec![0; length];
+    rng.fill_bytes(&mut bytes);
+
+    G60String::encode(&bytes)
+}
+
+/// Generates a random canonical G60 string that encodes `length` bytes and starts with
+/// the encoded `prefix`, e.g. to namespace identifiers, without generating and retrying.
+///
+/// The bytes constrained by the prefix are sampled uniformly among the ones whose encoding
+/// starts with it, and the rest are taken from `rng`.
+///
+/// ```rust
+/// # use rand::SeedableRng;
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+/// let id = g60::token::random_with_prefix("Tnt", 16, &mut rng).unwrap();
+///
+/// assert!(id.starts_with("Tnt"));
+/// assert_eq!(id.decode().len(), 16);
+/// ```
+///
+/// # Errors
+/// An error will be thrown if `prefix` is longer than the encoding of `length` bytes,
+/// contains any char outside the G60 alphabet, or no canonical string starts with it.
+#[cfg(feature = "rand_core")]
+pub fn random_with_prefix<R: rand_core::RngCore + ?Sized>(
+    prefix: &str,
+    length: usize,
+    rng: &mut R,
+) -> Result<G60String, VerificationError> {
+    let prefix = prefix.as_bytes();
+
+    if prefix.len() > compute_encoded_size(length) {
+        return Err(VerificationError::InvalidLength);
+    }
+
+    if let Some(index) = prefix
+        .iter()
+        .position(|v| *UTF8_TO_ENCODED_MAP.get(*v as usize).unwrap_or(&255) == 255)
+    {
+        return Err(VerificationError::InvalidByte {
+            index,
+            byte: prefix[index],
+        });
+    }
+
+    let mut bytes = vec![0; length];
+    rng.fill_bytes(&mut bytes);
+
+    for (group, chunk) in prefix.chunks(11).zip(bytes.chunks_mut(8)) {
+        let value = sample_group_with_prefix(group, chunk.len(), rng)?;
+        chunk.copy_from_slice(&value.to_be_bytes()[8 - chunk.len()..]);
+    }
+
+    Ok(G60String::encode(&bytes))
+}
+
+/// Generates a random canonical G60 string that encodes `length` bytes starting with the
+/// bytes of `prefix`, and the rest taken from `rng`.
+///
+/// # Panics
+/// Panics if `prefix` is longer than `length`.
+#[cfg(feature = "rand_core")]
+pub fn random_with_decoded_prefix<R: rand_core::RngCore + ?Sized>(
+    prefix: &[u8],
+    length: usize,
+    rng: &mut R,
+) -> G60String {
+    assert!(
+        prefix.len() <= length,
+        "The prefix must not be longer than the length"
+    );
+
+    let mut bytes = vec![0; length];
+    bytes[..prefix.len()].copy_from_slice(prefix);
+    rng.fill_bytes(&mut bytes[prefix.len()..]);
+
+    G60String::encode(&bytes)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An endless iterator of random canonical G60 strings that encode `length` bytes taken
+/// from an RNG, to generate test data or load in bulk.
+///
+/// ```rust
+/// # use rand::SeedableRng;
+/// # use g60::token::RandomG60Strings;
+/// let rng = rand::rngs::StdRng::seed_from_u64(0);
+/// let tokens: Vec<_> = RandomG60Strings::new(16, rng).take(3).collect();
+///
+/// assert_eq!(tokens.len(), 3);
+/// assert!(tokens.iter().all(|v| v.len() == 22));
+/// ```
+#[cfg(feature = "rand_core")]
+#[derive(Debug, Clone)]
+pub struct RandomG60Strings<R> {
+    length: usize,
+    rng: R,
+}
+
+#[cfg(feature = "rand_core")]
+impl<R: rand_core::RngCore> RandomG60Strings<R> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds an iterator of strings that encode `length` bytes taken from `rng`.
+    pub fn new(length: usize, rng: R) -> RandomG60Strings<R> {
+        RandomG60Strings { length, rng }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Returns the number of bytes encoded in every string.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Returns the inner RNG.
+    pub fn into_rng(self) -> R {
+        self.rng
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<R: rand_core::RngCore> Iterator for RandomG60Strings<R> {
+    type Item = G60String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(random_bytes_with_rng(self.length, &mut self.rng))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<R: rand_core::RngCore> std::iter::FusedIterator for RandomG60Strings<R> {}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The number of random bytes generated at once by [fill_random].
+#[cfg(any(feature = "getrandom", feature = "rand_core"))]
+const RANDOM_BLOCK_LENGTH: usize = 64;
+
+/// Fills `buffer` with the encoding of the random bytes generated by `fill` in blocks of
+/// [RANDOM_BLOCK_LENGTH] bytes, up to the largest valid length that fits in `buffer`.
+#[cfg(any(feature = "getrandom", feature = "rand_core"))]
+fn fill_random(buffer: &mut [u8], mut fill: impl FnMut(&mut [u8])) -> &str {
+    let length = compute_decoded_size(buffer.len());
+    let encoded_length = compute_encoded_size(length);
+    let mut block = scratch_buffer::<RANDOM_BLOCK_LENGTH>();
+
+    let encoded_blocks = buffer[..encoded_length].chunks_mut(RANDOM_BLOCK_LENGTH / 8 * 11);
+    for (index, encoded_block) in encoded_blocks.enumerate() {
+        let block_length = (length - index * RANDOM_BLOCK_LENGTH).min(RANDOM_BLOCK_LENGTH);
+        fill(&mut block[..block_length]);
+        encode_in_slice(&block[..block_length], encoded_block).unwrap();
+    }
+
+    // SAFETY: the encoded string contains only ASCII characters.
+    unsafe { std::str::from_utf8_unchecked(&buffer[..encoded_length]) }
+}
+
+/// Samples uniformly the big-endian value of a group of `length` bytes whose encoding
+/// starts with the chars of `prefix`.
+///
+/// The encoding of the groups preserves their order, so the values whose encoding starts
+/// with `prefix` form a range that is found by binary search.
+#[cfg(feature = "rand_core")]
+fn sample_group_with_prefix<R: rand_core::RngCore + ?Sized>(
+    prefix: &[u8],
+    length: usize,
+    rng: &mut R,
+) -> Result<u64, VerificationError> {
+    let group_prefix = |value: u64| {
+        let encoded = encoding_compute_chunk(&value.to_be_bytes()[8 - length..]);
+        encoded[..prefix.len()].cmp(prefix)
+    };
+    let partition_point = |predicate: &dyn Fn(u64) -> bool| {
+        let (mut low, mut high) = (0u128, 1u128 << (length * 8));
+        while low < high {
+            let middle = (low + high) / 2;
+            if predicate(middle as u64) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        low
+    };
+
+    let start = partition_point(&|v| group_prefix(v).is_lt());
+    let end = partition_point(&|v| group_prefix(v).is_le());
+
+    if start == end {
+        return Err(VerificationError::NotCanonical);
+    }
+
+    Ok((start + uniform_below(end - start, rng)) as u64)
+}
+
+/// Samples uniformly a value in `0..range`, rejecting the values of the incomplete last
+/// zone to avoid the modulo bias.
+#[cfg(feature = "rand_core")]
+fn uniform_below<R: rand_core::RngCore + ?Sized>(range: u128, rng: &mut R) -> u128 {
+    let zone = (1u128 << 64) / range * range;
+
+    loop {
+        let value = rng.next_u64() as u128;
+        if value < zone {
+            return value % range;
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for expires_at in [0, 1, 1_700_000_000, u64::MAX] {
+            let token = G60Token::new(expires_at, [7; 16]);
+            let encoded = token.encode();
+            let checked = token.encode_with_check();
+
+            assert_eq!(encoded.len(), 33, "Incorrect for {}", expires_at);
+            assert_eq!(checked.len(), 39, "Incorrect for {}", expires_at);
+            assert_eq!(
+                G60Token::decode(&encoded),
+                Ok(token),
+                "Incorrect for {}",
+                expires_at
+            );
+            assert_eq!(
+                G60Token::decode_with_check(&checked),
+                Ok(token),
+                "Incorrect for {}",
+                expires_at
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let token = G60Token::new(100, [0; 4]);
+
+        assert!(!token.is_expired_at(99));
+        assert!(token.is_expired_at(100));
+        assert!(token.is_expired_at(101));
+        assert!(token.is_expired());
+        assert!(!G60Token::new(u64::MAX, [0; 4]).is_expired());
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_generate_token() {
+        for (entropy_bits, length) in [(0, 0), (1, 2), (8, 2), (9, 3), (64, 11), (128, 22)] {
+            let token = generate_token(entropy_bits);
+
+            assert_eq!(token.len(), length, "Incorrect for {}", entropy_bits);
+        }
+
+        assert_ne!(generate_token(128), generate_token(128));
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_with_rng() {
+        use rand::SeedableRng;
+
+        let token = generate_token_with_rng(128, &mut rand::rngs::StdRng::seed_from_u64(0));
+        let bytes = random_bytes_with_rng(16, &mut rand::rngs::StdRng::seed_from_u64(0));
+
+        assert_eq!(token.len(), 22);
+        assert_eq!(token, bytes);
+        assert_ne!(
+            token,
+            generate_token_with_rng(128, &mut rand::rngs::StdRng::seed_from_u64(1))
+        );
+
+        let rng: &mut dyn rand_core::RngCore = &mut rand::rngs::StdRng::seed_from_u64(0);
+        assert_eq!(random_bytes_with_rng(16, rng), token);
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_random_into() {
+        for (buffer_length, length) in [
+            (0, 0),
+            (1, 0),
+            (2, 2),
+            (4, 3),
+            (8, 7),
+            (11, 11),
+            (12, 11),
+            (200, 200),
+            (203, 203),
+        ] {
+            let mut buffer = vec![0; buffer_length];
+            let token = random_into(&mut buffer);
+
+            assert_eq!(token.len(), length, "Incorrect for {}", buffer_length);
+            assert_eq!(
+                crate::verify(token),
+                Ok(()),
+                "Incorrect for {}",
+                buffer_length
+            );
+        }
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_random_into_with_rng() {
+        use rand::SeedableRng;
+
+        let mut buffer = [0; 200];
+        let token = random_into_with_rng(&mut buffer, &mut rand::rngs::StdRng::seed_from_u64(0));
+        let expected = random_bytes_with_rng(145, &mut rand::rngs::StdRng::seed_from_u64(0));
+
+        assert_eq!(token, expected.as_str());
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_random_g60_strings() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let tokens: Vec<_> = RandomG60Strings::new(13, rand::rngs::StdRng::seed_from_u64(0))
+            .take(100)
+            .collect();
+
+        for token in &tokens {
+            assert_eq!(token, &random_bytes_with_rng(13, &mut rng));
+        }
+
+        assert_ne!(tokens[0], tokens[1]);
+        assert_eq!(
+            RandomG60Strings::new(0, rng).nth(1000).map(|v| v.len()),
+            Some(0)
+        );
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_random_with_prefix() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        for length in [0, 1, 2, 7, 8, 13, 20] {
+            let encoded = G60String::encode(&b"Hello, world! Hello!"[..length]);
+
+            for prefix_length in 0..=encoded.len() {
+                let prefix = &encoded[..prefix_length];
+                let result = random_with_prefix(prefix, length, &mut rng)
+                    .expect("The generation must succeed");
+
+                assert!(
+                    result.starts_with(prefix),
+                    "Incorrect for '{}' and {}",
+                    prefix,
+                    length
+                );
+                assert_eq!(result.decode().len(), length);
+            }
+        }
+
+        // The random part varies.
+        let a = random_with_prefix("Gt4", 8, &mut rng).unwrap();
+        let b = random_with_prefix("Gt4", 8, &mut rng).unwrap();
+        assert_ne!(a, b);
+
+        let result = random_with_decoded_prefix(b"tenant", 16, &mut rng);
+        assert_eq!(&result.decode()[..6], b"tenant");
+
+        for (prefix, length, error) in [
+            ("Gt4", 1, VerificationError::InvalidLength),
+            (
+                "GtO",
+                8,
+                VerificationError::InvalidByte {
+                    index: 2,
+                    byte: b'O',
+                },
+            ),
+            ("zzzzzzzzzzz", 8, VerificationError::NotCanonical),
+            ("0f", 1, VerificationError::NotCanonical),
+        ] {
+            assert_eq!(
+                random_with_prefix(prefix, length, &mut rng),
+                Err(error),
+                "Incorrect for '{}'",
+                prefix
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let token = G60Token::new(1_700_000_000, [7; 16]);
+
+        assert_eq!(
+            G60Token::<8>::decode(&token.encode()),
+            Err(DecodingError::UnexpectedDecodedLength {
+                expected: 16,
+                actual: 24,
+            })
+        );
+
+        // Alter the expiry timestamp.
+        let mut checked = token.encode_with_check().into_string();
+        checked.replace_range(0..1, "1");
+
+        assert_eq!(
+            G60Token::<16>::decode_with_check(&checked),
+            Err(DecodingError::ChecksumMismatch)
+        );
+    }
+}
@@ -1,4 +1,38 @@
-#[inline]
+/// Always inlined so that, with the constant divisors used by the chunk math, the compiler
+/// replaces the division with a reciprocal multiplication and a shift.
+#[inline(always)]
 pub fn div_rem(dividend: usize, divisor: usize) -> (usize, usize) {
     (dividend / divisor, dividend % divisor)
 }
+
+/// A temporary buffer for encoded or decoded content that, with the `zeroize` feature,
+/// is zeroed on drop so its content does not linger in memory.
+#[cfg(feature = "zeroize")]
+pub(crate) type ScratchBuffer<const N: usize> = zeroize::Zeroizing<[u8; N]>;
+
+/// A temporary buffer for encoded or decoded content that, with the `zeroize` feature,
+/// is zeroed on drop so its content does not linger in memory.
+#[cfg(not(feature = "zeroize"))]
+pub(crate) type ScratchBuffer<const N: usize> = [u8; N];
+
+/// A growable [ScratchBuffer].
+#[cfg(feature = "zeroize")]
+pub(crate) type ScratchVec = zeroize::Zeroizing<Vec<u8>>;
+
+/// A growable [ScratchBuffer].
+#[cfg(not(feature = "zeroize"))]
+pub(crate) type ScratchVec = Vec<u8>;
+
+/// Builds a zeroed [ScratchBuffer].
+#[inline(always)]
+pub(crate) fn scratch_buffer<const N: usize>() -> ScratchBuffer<N> {
+    #[cfg(feature = "zeroize")]
+    {
+        zeroize::Zeroizing::new([0; N])
+    }
+
+    #[cfg(not(feature = "zeroize"))]
+    {
+        [0; N]
+    }
+}
@@ -0,0 +1,41 @@
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::G60String;
+
+/// The pattern of the valid G60 encoded strings: complete groups of 11 chars followed by
+/// an incomplete group whose length is not 1, 4 or 8. It cannot check the canonicality.
+const PATTERN: &str = "^(?:[0-9A-HJ-NP-Za-z]{11})*(?:[0-9A-HJ-NP-Za-z]{2,3}|[0-9A-HJ-NP-Za-z]{5,7}|[0-9A-HJ-NP-Za-z]{9,10})?$";
+
+impl PartialSchema for G60String {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("A canonical G60 encoded string."))
+            .pattern(Some(PATTERN))
+            .examples(["Gt4CGFiHehzRzjCF16"])
+            .into()
+    }
+}
+
+impl ToSchema for G60String {}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        let schema = serde_json::to_value(G60String::schema()).unwrap();
+
+        assert_eq!(G60String::name(), "G60String");
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["pattern"], PATTERN);
+        assert_eq!(schema["examples"][0], "Gt4CGFiHehzRzjCF16");
+    }
+}
@@ -0,0 +1,101 @@
+use uuid::Uuid;
+
+use crate::errors::DecodingError;
+use crate::{decode_exact, G60Str, G60String};
+
+/// Encodes a UUID as a fixed-length G60 string of 22 chars. The encoded strings sort in
+/// the same order as the bytes of the UUIDs, so time-ordered UUIDs, like v7, stay sorted.
+///
+/// ```rust
+/// # use uuid::Uuid;
+/// let uuid = Uuid::from_u128(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+/// let encoded = g60::encode_uuid(uuid);
+///
+/// assert_eq!(encoded.len(), 22);
+/// assert_eq!(g60::decode_uuid(&encoded).unwrap(), uuid);
+/// ```
+pub fn encode_uuid(uuid: Uuid) -> G60String {
+    G60String::encode(uuid.as_bytes())
+}
+
+/// Decodes a UUID encoded with [encode_uuid].
+///
+/// # Errors
+/// An error will be thrown if `encoded` does not have 22 chars or it is not a valid
+/// canonical G60 encoded string.
+pub fn decode_uuid(encoded: &str) -> Result<Uuid, DecodingError> {
+    Ok(Uuid::from_bytes(decode_exact(encoded)?))
+}
+
+impl From<Uuid> for G60String {
+    fn from(uuid: Uuid) -> Self {
+        encode_uuid(uuid)
+    }
+}
+
+impl TryFrom<&G60Str> for Uuid {
+    type Error = DecodingError;
+
+    fn try_from(value: &G60Str) -> Result<Self, Self::Error> {
+        decode_uuid(value.as_str())
+    }
+}
+
+impl TryFrom<G60String> for Uuid {
+    type Error = DecodingError;
+
+    fn try_from(value: G60String) -> Result<Self, Self::Error> {
+        Uuid::try_from(value.as_g60_str())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TESTS ----------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut random = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..1000 {
+            let (a, b) = (Uuid::from_u128(random.gen()), Uuid::from_u128(random.gen()));
+            let (encoded_a, encoded_b) = (G60String::from(a), G60String::from(b));
+
+            assert_eq!(encoded_a.len(), 22, "Incorrect for {}", a);
+            assert_eq!(encoded_a.cmp(&encoded_b), a.cmp(&b), "Incorrect for {}", a);
+            assert_eq!(
+                Uuid::try_from(encoded_a.as_g60_str()),
+                Ok(a),
+                "Incorrect for {}",
+                a
+            );
+            assert_eq!(Uuid::try_from(encoded_a), Ok(a), "Incorrect for {}", a);
+        }
+
+        for uuid in [Uuid::nil(), Uuid::max()] {
+            assert_eq!(
+                decode_uuid(&encode_uuid(uuid)),
+                Ok(uuid),
+                "Incorrect for {}",
+                uuid
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode_uuid("Gt4CGFiHehzRzjCF16"),
+            Err(DecodingError::UnexpectedDecodedLength {
+                expected: 16,
+                actual: 13,
+            })
+        );
+    }
+}
@@ -1,5 +1,8 @@
-use crate::decoding::{compute_chunk, compute_decoded_size};
-use crate::errors::VerificationError;
+use std::fs::File;
+use std::path::Path;
+
+use crate::decoding::{compute_chunk, compute_decoded_size, decode_last_group};
+use crate::errors::{FileVerificationError, VerificationError};
 
 /// Verifies `content` is a valid G60 encoded string.
 ///
@@ -37,6 +40,110 @@ pub fn verify(encoded: &str) -> Result<(), VerificationError> {
     Ok(())
 }
 
+/// Verifies the content of the file at `path` is a valid G60 encoded string, processing it
+/// group by group so files of any size can be verified.
+///
+/// With the `mmap` feature the file is memory-mapped, otherwise it is read through a fixed
+/// buffer.
+///
+/// # Errors
+/// An error will be thrown in the following cases:
+/// - if the file cannot be read.
+/// - if the content is not a valid G60 encoded string or it is not canonical. The error
+///   contains the offset of the first invalid position.
+pub fn verify_file<P: AsRef<Path>>(path: P) -> Result<(), FileVerificationError> {
+    let file = File::open(path)?;
+    let file_length = file.metadata()?.len();
+
+    // Check length, like verify, before the groups.
+    if let 1 | 4 | 8 = file_length % 11 {
+        return Err(FileVerificationError::Verification {
+            offset: (file_length / 11 * 11) as usize,
+            error: VerificationError::InvalidLength,
+        });
+    }
+
+    #[cfg(feature = "mmap")]
+    {
+        if file_length == 0 {
+            return Ok(());
+        }
+
+        // SAFETY: the map is only read while the file is open. As with any memory-mapped
+        // file, concurrent modifications by other processes can alter the result.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        let complete_groups_length = map.len() / 11 * 11;
+
+        verify_groups_with_offset(0, &map[..complete_groups_length])?;
+        verify_last_group_with_offset(complete_groups_length, &map[complete_groups_length..])
+    }
+
+    #[cfg(not(feature = "mmap"))]
+    {
+        use crate::constants::BLOCK_GROUPS;
+        use std::io::{ErrorKind, Read};
+
+        let mut file = file;
+        let mut buffer = [0; BLOCK_GROUPS * 11 * 16];
+        let mut buffer_length = 0;
+        let mut chunk_index = 0;
+
+        loop {
+            let read = match file.read(&mut buffer[buffer_length..]) {
+                Ok(0) => break,
+                Ok(v) => v,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            buffer_length += read;
+
+            let complete_groups_length = buffer_length / 11 * 11;
+            verify_groups_with_offset(chunk_index, &buffer[..complete_groups_length])?;
+            chunk_index += complete_groups_length;
+
+            buffer.copy_within(complete_groups_length..buffer_length, 0);
+            buffer_length -= complete_groups_length;
+        }
+
+        verify_last_group_with_offset(chunk_index, &buffer[..buffer_length])
+    }
+}
+
+// ----------------------------------------------------------------------------
+// AUX METHODS ----------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Verifies complete groups, `chunk_index` being the offset of `bytes` in the whole content.
+fn verify_groups_with_offset(
+    mut chunk_index: usize,
+    bytes: &[u8],
+) -> Result<(), FileVerificationError> {
+    for chunk in bytes.chunks_exact(11) {
+        compute_chunk(chunk_index, chunk).map_err(|error| with_offset(chunk_index, error))?;
+        chunk_index += 11;
+    }
+
+    Ok(())
+}
+
+fn verify_last_group_with_offset(
+    chunk_index: usize,
+    chunk: &[u8],
+) -> Result<(), FileVerificationError> {
+    decode_last_group(chunk_index, chunk).map_err(|error| with_offset(chunk_index, error))?;
+
+    Ok(())
+}
+
+fn with_offset(chunk_index: usize, error: VerificationError) -> FileVerificationError {
+    let offset = match error {
+        VerificationError::InvalidByte { index, .. } => index,
+        _ => chunk_index,
+    };
+
+    FileVerificationError::Verification { offset, error }
+}
+
 // ----------------------------------------------------------------------------
 // TESTS ----------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -44,7 +151,10 @@ pub fn verify(encoded: &str) -> Result<(), VerificationError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::encode;
+    use crate::constants::{BLOCK_GROUPS, ENCODED_TO_UTF8_MAP};
+    use crate::{decode, encode};
+    use rand::{Rng, SeedableRng};
+    use std::io::Write;
 
     #[test]
     fn test_verify_ok() {
@@ -142,7 +252,7 @@ mod tests {
 
     #[test]
     fn test_not_canonical() {
-        for i in ["0f", "2F", "5y", "BU", "Gv", "Nr", "Xd"] {
+        for i in ["0f", "2F", "5y", "BU", "Gv", "Nr", "Xd", "0000Kz00000"] {
             assert_eq!(
                 verify(i),
                 Err(VerificationError::NotCanonical),
@@ -151,4 +261,96 @@ mod tests {
             );
         }
     }
+
+    /// This test checks that a string is verified iff it is the encoding of its decoded bytes.
+    #[test]
+    fn test_verify_matches_round_trip() {
+        let mut random = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..100_000 {
+            let length = random.gen_range(0..=33);
+            let encoded: String = (0..length)
+                .map(|_| ENCODED_TO_UTF8_MAP[random.gen_range(0..60)] as char)
+                .collect();
+
+            let is_round_trip = match decode(&encoded) {
+                Ok(decoded) => encode(&decoded) == encoded,
+                Err(_) => false,
+            };
+
+            assert_eq!(
+                verify(&encoded).is_ok(),
+                is_round_trip,
+                "Incorrect for '{}'",
+                encoded
+            );
+        }
+    }
+
+    fn verify_content(name: &str, content: &[u8]) -> Result<(), FileVerificationError> {
+        let path =
+            std::env::temp_dir().join(format!("g60-verify-file-{}-{name}", std::process::id()));
+        File::create(&path).unwrap().write_all(content).unwrap();
+
+        let result = verify_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        result
+    }
+
+    #[test]
+    fn test_verify_file() {
+        let bytes: Vec<u8> = (0..=255u8)
+            .cycle()
+            .take(BLOCK_GROUPS * 8 * 40 + 3)
+            .collect();
+        let encoded = encode(&bytes).into_bytes();
+
+        verify_content("empty", b"").expect("The verification must succeed");
+        verify_content("ok", &encoded).expect("The verification must succeed");
+
+        let mut invalid = encoded.clone();
+        invalid[BLOCK_GROUPS * 11 * 20 + 4] = b'I';
+        assert_eq!(
+            verify_content("invalid-byte", &invalid),
+            Err(FileVerificationError::Verification {
+                offset: BLOCK_GROUPS * 11 * 20 + 4,
+                error: VerificationError::InvalidByte {
+                    index: BLOCK_GROUPS * 11 * 20 + 4,
+                    byte: b'I',
+                },
+            })
+        );
+
+        let mut invalid = encoded.clone();
+        invalid.extend_from_slice(b"000");
+        assert_eq!(
+            verify_content("invalid-length", &invalid),
+            Err(FileVerificationError::Verification {
+                offset: encoded.len() / 11 * 11,
+                error: VerificationError::InvalidLength,
+            })
+        );
+
+        // The length is checked before the groups, like in verify.
+        let test = b"0000Kz000000";
+        assert_eq!(
+            verify(std::str::from_utf8(test).unwrap()),
+            Err(VerificationError::InvalidLength)
+        );
+        assert_eq!(
+            verify_content("length-before-groups", test),
+            Err(FileVerificationError::Verification {
+                offset: 11,
+                error: VerificationError::InvalidLength,
+            })
+        );
+
+        assert_eq!(
+            verify_file(std::env::temp_dir().join("g60-verify-file-missing")),
+            Err(FileVerificationError::ReadingError(
+                std::io::ErrorKind::NotFound
+            ))
+        );
+    }
 }